@@ -14,7 +14,7 @@ use crate::utils::validation_i18n::translate_validation_error;
 pub fn IntroPage() -> impl IntoView {
     let i18n = use_i18n();
     let example_code = r#"
-    use borang::{Field, Form, FormComponent, FormValidation, FromFieldValue, Input, Select, ValidationError};
+    use borang::{ErrorMessage, Field, Form, FormComponent, FormValidation, FromFieldValue, Input, Select, ValidationError};
     use leptos::prelude::*;
 
     #[derive(Clone, Debug)]
@@ -100,61 +100,45 @@ pub fn IntroPage() -> impl IntoView {
                     <Field form=form name="name" let:field_state>
                         <label for="name">"Name"</label>
                         <Input
-                            state=field_state
-                            attr:id="name"
-                            attr:type="text"
-                            attr:placeholder="Jed Saw"
+                            state=field_state.clone()
+                            id="name"
+                            input_type="text"
+                            placeholder="Jed Saw"
                         />
-                        <Show when=move || field_state.has_error()>
-                            <span class="error">
-                                {move || { field_state.get_error().map(|e| e.message().to_string()) }}
-                            </span>
-                        </Show>
+                        <ErrorMessage state=field_state class="error" />
                     </Field>
 
                     <Field form=form name="email" let:field_state>
                         <label for="email">"Email"</label>
                         <Input
-                            state=field_state
-                            attr:id="email"
-                            attr:type="email"
-                            attr:placeholder="jed@borang.com"
+                            state=field_state.clone()
+                            id="email"
+                            input_type="email"
+                            placeholder="jed@borang.com"
                         />
-                        <Show when=move || field_state.has_error()>
-                            <span class="error">
-                                {move || { field_state.get_error().map(|e| e.message().to_string()) }}
-                            </span>
-                        </Show>
+                        <ErrorMessage state=field_state class="error" />
                     </Field>
 
                     <Field form=form name="age" let:field_state>
                         <label for="age">"Age"</label>
                         <Input
-                            state=field_state
-                            attr:id="age"
-                            attr:type="number"
-                            attr:placeholder="18"
+                            state=field_state.clone()
+                            id="age"
+                            input_type="number"
+                            placeholder="18"
                         />
-                        <Show when=move || field_state.has_error()>
-                            <span class="error">
-                                {move || { field_state.get_error().map(|e| e.message().to_string()) }}
-                            </span>
-                        </Show>
+                        <ErrorMessage state=field_state class="error" />
                     </Field>
 
                     <Field form=form name="country" let:field_state>
                         <label for="country">"Country"</label>
-                        <Select state=field_state attr:id="country">
+                        <Select state=field_state.clone() id="country">
                             <option value="Malaysia">"Malaysia"</option>
                             <option value="Australia">"Australia"</option>
                             <option value="England">"England"</option>
                             <option value="Other">"Other"</option>
                         </Select>
-                        <Show when=move || field_state.has_error()>
-                            <span class="error">
-                                {move || { field_state.get_error().map(|e| e.message().to_string()) }}
-                            </span>
-                        </Show>
+                        <ErrorMessage state=field_state class="error" />
                     </Field>
 
                     <div>
@@ -247,12 +231,13 @@ where
 
     let error_message = Signal::derive(move || {
         state
-            .get_error()
+            .err
+            .get()
             .map(|e| translate_validation_error(i18n, &e, field_name.get()))
     });
 
     view! {
-        <Show when=move || state.has_error()>
+        <Show when=move || state.err.with(Option::is_some)>
             <span class="block mt-1 text-sm text-red-500">{error_message}</span>
         </Show>
     }
@@ -292,12 +277,12 @@ fn ExampleForm() -> impl IntoView {
                             {t!(i18n, name)}
                         </label>
                         <Input
-                            state=field_state
-                            attr:id="name"
-                            attr:r#type="text"
-                            attr:placeholder="Jed Saw"
+                            state=field_state.clone()
+                            id="name"
+                            input_type="text"
+                            placeholder="Jed Saw"
                             class="py-2 px-3 w-full rounded-md border focus:ring-2 focus:outline-none border-border bg-background focus:ring-primary"
-                            class:border-red-500=move || field_state.has_error()
+                            class:border-red-500=move || field_state.err.with(Option::is_some)
                         />
                         <FieldError
                             state=field_state
@@ -312,12 +297,12 @@ fn ExampleForm() -> impl IntoView {
                             {t!(i18n, email)}
                         </label>
                         <Input
-                            state=field_state
+                            state=field_state.clone()
                             class="py-2 px-3 w-full rounded-md border focus:ring-2 focus:outline-none border-border bg-background focus:ring-primary"
-                            class:border-red-500=move || field_state.has_error()
-                            attr:id="email"
-                            attr:r#type="email"
-                            attr:placeholder="jed@inspire.my"
+                            class:border-red-500=move || field_state.err.with(Option::is_some)
+                            id="email"
+                            input_type="email"
+                            placeholder="jed@inspire.my"
                         />
                         <FieldError
                             state=field_state
@@ -332,12 +317,12 @@ fn ExampleForm() -> impl IntoView {
                             {t!(i18n, age)}
                         </label>
                         <Input
-                            state=field_state
+                            state=field_state.clone()
                             class="py-2 px-3 w-full rounded-md border focus:ring-2 focus:outline-none border-border bg-background focus:ring-primary"
-                            class:border-red-500=move || field_state.has_error()
-                            attr:id="age"
-                            attr:r#type="number"
-                            attr:placeholder="18"
+                            class:border-red-500=move || field_state.err.with(Option::is_some)
+                            id="age"
+                            input_type="number"
+                            placeholder="18"
                         />
                         <FieldError
                             state=field_state
@@ -352,10 +337,10 @@ fn ExampleForm() -> impl IntoView {
                             {t!(i18n, country)}
                         </label>
                         <Select
-                            state=field_state
-                            attr:id="country"
+                            state=field_state.clone()
+                            id="country"
                             class="py-2 px-3 w-full rounded-md border focus:ring-2 focus:outline-none border-border bg-background focus:ring-primary"
-                            class:border-red-500=move || field_state.has_error()
+                            class:border-red-500=move || field_state.err.with(Option::is_some)
                         >
                             <option value="Malaysia">"Malaysia"</option>
                             <option value="Australia">"Australia"</option>
@@ -38,9 +38,20 @@ pub fn translate_validation_error(
         ErrorKind::Required { .. } => {
             t_string!(i18n, item_is_required, item = field_name).to_string()
         }
-        ErrorKind::InvalidEmail { .. } => {
+        ErrorKind::InvalidEmail { .. }
+        | ErrorKind::InvalidUuid { .. }
+        | ErrorKind::InvalidHexColor { .. } => {
             t_string!(i18n, item_is_not_valid, item = field_name).to_string()
         }
+        ErrorKind::InvalidLength {
+            equal: Some(equal), ..
+        } => t_string!(
+            i18n,
+            item_must_be_exactly_chars,
+            item = field_name,
+            equal = equal
+        )
+        .to_string(),
         ErrorKind::InvalidLength {
             min: Some(min),
             max: Some(max),
@@ -78,6 +89,46 @@ pub fn translate_validation_error(
         ErrorKind::InvalidLength { .. } => {
             t_string!(i18n, item_is_not_valid, item = field_name).to_string()
         }
+        ErrorKind::InvalidItemCount {
+            min: Some(min),
+            max: Some(max),
+            ..
+        } => t_string!(
+            i18n,
+            item_must_have_between_items,
+            item = field_name,
+            min = min,
+            max = max
+        )
+        .to_string(),
+        ErrorKind::InvalidItemCount {
+            min: Some(min),
+            max: None,
+            ..
+        } => t_string!(
+            i18n,
+            item_must_have_at_least_items,
+            item = field_name,
+            min = min
+        )
+        .to_string(),
+        ErrorKind::InvalidItemCount {
+            min: None,
+            max: Some(max),
+            ..
+        } => t_string!(
+            i18n,
+            item_must_have_at_most_items,
+            item = field_name,
+            max = max
+        )
+        .to_string(),
+        ErrorKind::InvalidDate { .. } => {
+            t_string!(i18n, item_is_not_valid, item = field_name).to_string()
+        }
+        ErrorKind::InvalidItemCount { .. } => {
+            t_string!(i18n, item_is_not_valid, item = field_name).to_string()
+        }
         ErrorKind::InvalidRange {
             min: Some(min),
             max: Some(max),
@@ -103,6 +154,13 @@ pub fn translate_validation_error(
         ErrorKind::InvalidRange { .. } => {
             t_string!(i18n, item_is_not_valid, item = field_name).to_string()
         }
+        ErrorKind::NotMultipleOf { .. }
+        | ErrorKind::InvalidSign { .. }
+        | ErrorKind::WeakPassword { .. }
+        | ErrorKind::FileTooLarge { .. }
+        | ErrorKind::InvalidFileType { .. } => {
+            t_string!(i18n, item_is_not_valid, item = field_name).to_string()
+        }
         ErrorKind::ParseError { expected_type, .. } => {
             let translated_type = translate_type_name(i18n, expected_type);
             t_string!(
@@ -113,6 +171,12 @@ pub fn translate_validation_error(
             )
             .to_string()
         }
+        ErrorKind::PatternMismatch { .. } | ErrorKind::NotOneOf { .. } => {
+            t_string!(i18n, item_is_not_valid, item = field_name).to_string()
+        }
         ErrorKind::Custom { message, .. } => message.clone(),
+        ErrorKind::CustomCode { .. } => {
+            t_string!(i18n, item_is_not_valid, item = field_name).to_string()
+        }
     }
 }
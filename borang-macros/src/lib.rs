@@ -1,61 +1,757 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{
-    parse::Parse, parse::ParseStream, parse_macro_input, Data, DeriveInput, Expr, ExprLit, Field,
-    Fields, Lit, Meta, Token,
+    ext::IdentExt, parse::Parse, parse::ParseStream, parse::Parser, parse_macro_input,
+    spanned::Spanned, Data, DeriveInput, Expr, ExprLit, Field, Fields, Lit, Meta, Token,
 };
 
 /// Internal representation of a validator and its parameters
 #[derive(Debug, Clone)]
 enum Validator {
     Required,
-    Email,
+    Email {
+        mode: EmailMode,
+    },
+    Uuid,
+    HexColor,
     Length {
-        min: Option<usize>,
-        max: Option<usize>,
+        min: Option<proc_macro2::TokenStream>,
+        max: Option<proc_macro2::TokenStream>,
+        equal: Option<proc_macro2::TokenStream>,
+        bytes: bool,
+    },
+    Items {
+        min: Option<proc_macro2::TokenStream>,
+        max: Option<proc_macro2::TokenStream>,
+    },
+    Date {
+        after: Option<DateBound>,
+        before: Option<DateBound>,
     },
     Range {
-        min: Option<i64>,
-        max: Option<i64>,
+        min: Option<RangeBound>,
+        max: Option<RangeBound>,
     },
     Custom {
-        method_name: String,
+        /// Method name, spanned at the attribute's string literal
+        method: syn::Ident,
+    },
+    CustomValue {
+        /// Function path, resolved against `Self` if it is a bare name
+        function: proc_macro2::TokenStream,
+        span: proc_macro2::Span,
+    },
+    Rule {
+        /// Expression evaluating to a `borang::rules::ValidationRule`
+        rule: proc_macro2::TokenStream,
+        span: proc_macro2::Span,
+    },
+    Any {
+        /// Validators of which at least one must pass
+        validators: Vec<(Validator, ValidatorOptions)>,
+    },
+    Registered {
+        /// Name the rule was registered under with `borang::rules::register_rule`
+        name: String,
+    },
+    Pattern {
+        pattern: String,
     },
+    OneOf {
+        allowed: Vec<String>,
+    },
+    Sign {
+        sign: SignConstraint,
+    },
+    MultipleOf {
+        step: RangeBound,
+    },
+    Password {
+        min_len: proc_macro2::TokenStream,
+        min_classes: proc_macro2::TokenStream,
+    },
+    File {
+        /// Largest allowed file size in bytes
+        max_size: Option<u64>,
+        /// HTML `accept` list, e.g. `"image/*,.pdf"`
+        accept: Option<String>,
+    },
+}
+
+/// Default minimum length for the password validator
+const DEFAULT_PASSWORD_MIN_LEN: usize = 8;
+
+/// Default number of character classes for the password validator
+const DEFAULT_PASSWORD_MIN_CLASSES: usize = 3;
+
+/// The sign a numeric field must have
+#[derive(Debug, Clone, Copy)]
+enum SignConstraint {
+    Positive,
+    NonNegative,
+    NonZero,
+}
+
+/// How strictly the email validator checks addresses
+#[derive(Debug, Clone, Copy, Default)]
+enum EmailMode {
+    #[default]
+    Simple,
+    Html5,
+    Rfc5322,
+}
+
+impl EmailMode {
+    /// Parse a `mode = "..."` parameter
+    fn from_lit(value: &syn::Lit) -> syn::Result<Self> {
+        if let syn::Lit::Str(lit_str) = value {
+            match lit_str.value().as_str() {
+                "simple" => Ok(EmailMode::Simple),
+                "html5" => Ok(EmailMode::Html5),
+                "rfc5322" => Ok(EmailMode::Rfc5322),
+                _ => Err(syn::Error::new_spanned(
+                    value,
+                    "email mode must be \"simple\", \"html5\" or \"rfc5322\"",
+                )),
+            }
+        } else {
+            Err(syn::Error::new_spanned(
+                value,
+                "mode parameter must be a string literal",
+            ))
+        }
+    }
+
+    /// The matching `borang::EmailMode` variant
+    fn to_tokens(self) -> proc_macro2::TokenStream {
+        match self {
+            EmailMode::Simple => quote! { borang::EmailMode::Simple },
+            EmailMode::Html5 => quote! { borang::EmailMode::Html5 },
+            EmailMode::Rfc5322 => quote! { borang::EmailMode::Rfc5322 },
+        }
+    }
+}
+
+/// A numeric bound for the range validator
+#[derive(Debug, Clone)]
+enum RangeBound {
+    Int(i64),
+    UInt(u64),
+    /// A float literal's value, and its digits for decimal fields
+    Float(f64, LitDigits),
+    /// A constant or expression, emitted verbatim in the field's type
+    Expr(proc_macro2::TokenStream),
+}
+
+/// The digits of a float literal as written, e.g. `0.1` or `1e-3`
+#[derive(Debug, Clone)]
+struct LitDigits {
+    digits: String,
+    span: proc_macro2::Span,
+}
+
+impl LitDigits {
+    /// The literal as a `rust_decimal::Decimal` mantissa and scale, or `None`
+    /// if it doesn't fit one
+    ///
+    /// Decimals hold a 96-bit mantissa and up to 28 decimal places. Trailing
+    /// zeros beyond those places are dropped, as they don't change the value.
+    fn decimal_parts(&self) -> Option<(i128, u32)> {
+        let (negative, digits) = match self.digits.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, self.digits.as_str()),
+        };
+        let (number, exponent) = match digits.split_once(['e', 'E']) {
+            Some((number, exponent)) => (number, exponent.parse::<i32>().ok()?),
+            None => (digits, 0),
+        };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        let mut mantissa: i128 = 0;
+        for c in whole.chars().chain(fraction.chars()) {
+            let digit = i128::from(c.to_digit(10)?);
+            mantissa = mantissa.checked_mul(10)?.checked_add(digit)?;
+        }
+        let mut scale = i32::try_from(fraction.len()).ok()?.checked_sub(exponent)?;
+        while scale > 28 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        if mantissa == 0 {
+            scale = 0;
+        }
+        while scale < 0 {
+            mantissa = mantissa.checked_mul(10)?;
+            scale += 1;
+        }
+        if scale > 28 || mantissa >= 1 << 96 {
+            return None;
+        }
+        Some((if negative { -mantissa } else { mantissa }, scale as u32))
+    }
+}
+
+impl RangeBound {
+    /// Parse a bound from an integer or float literal
+    ///
+    /// Integers that don't fit in `i64` are kept as `u64` so bounds for large
+    /// unsigned fields aren't lost.
+    fn from_lit(name: &str, value: &syn::Lit) -> syn::Result<Self> {
+        match value {
+            syn::Lit::Int(lit_int) => {
+                if let Ok(parsed) = lit_int.base10_parse::<i64>() {
+                    return Ok(RangeBound::Int(parsed));
+                }
+                lit_int
+                    .base10_parse::<u64>()
+                    .map(RangeBound::UInt)
+                    .map_err(|e| {
+                        syn::Error::new_spanned(lit_int, format!("Invalid {} value: {}", name, e))
+                    })
+            }
+            syn::Lit::Float(lit_float) => lit_float
+                .base10_parse::<f64>()
+                .map(|value| {
+                    let digits = LitDigits {
+                        digits: lit_float.base10_digits().to_string(),
+                        span: lit_float.span(),
+                    };
+                    RangeBound::Float(value, digits)
+                })
+                .map_err(|e| {
+                    syn::Error::new_spanned(lit_float, format!("Invalid {} value: {}", name, e))
+                }),
+            _ => Err(syn::Error::new_spanned(
+                value,
+                format!("{} parameter must be a number", name),
+            )),
+        }
+    }
+
+    fn is_float(&self) -> bool {
+        matches!(self, RangeBound::Float(..))
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            RangeBound::Int(v) => *v == 0,
+            RangeBound::UInt(v) => *v == 0,
+            RangeBound::Float(v, _) => *v == 0.0,
+            RangeBound::Expr(_) => false,
+        }
+    }
+
+    /// Unsuffixed literal for comparing against the field value
+    ///
+    /// Unsuffixed literals take on the field's own numeric type, so the
+    /// comparison happens natively without casting the field. `cast` is set when
+    /// an integer field is compared as `f64`, which expressions must follow.
+    fn to_compare_tokens(&self, as_float: bool, cast: bool) -> proc_macro2::TokenStream {
+        let lit = match *self {
+            RangeBound::Int(v) if as_float => proc_macro2::Literal::f64_unsuffixed(v as f64),
+            RangeBound::UInt(v) if as_float => proc_macro2::Literal::f64_unsuffixed(v as f64),
+            RangeBound::Int(v) => proc_macro2::Literal::i64_unsuffixed(v),
+            RangeBound::UInt(v) => proc_macro2::Literal::u64_unsuffixed(v),
+            RangeBound::Float(v, _) => proc_macro2::Literal::f64_unsuffixed(v),
+            RangeBound::Expr(ref expr) if cast => return quote! { ((#expr) as f64) },
+            RangeBound::Expr(ref expr) => return quote! { (#expr) },
+        };
+        quote! { #lit }
+    }
+
+    /// Exact `Decimal` constant of the bound for a decimal field
+    ///
+    /// Literals are built from their digits as written, so `0.1` is exactly
+    /// one tenth instead of the nearest `f64`. Literals that don't fit a
+    /// `Decimal` are a compile error rather than a panic at validation time.
+    fn to_decimal_tokens(&self, ty: &syn::Type) -> syn::Result<proc_macro2::TokenStream> {
+        let (mantissa, scale) = match *self {
+            RangeBound::Int(v) => (i128::from(v), 0),
+            RangeBound::UInt(v) => (i128::from(v), 0),
+            RangeBound::Float(_, ref digits) => digits.decimal_parts().ok_or_else(|| {
+                syn::Error::new(
+                    digits.span,
+                    "bound doesn't fit a Decimal, which holds a 96-bit integer and up to 28 decimal places",
+                )
+            })?,
+            RangeBound::Expr(ref expr) => return Ok(quote! { (#expr) }),
+        };
+        // `from_parts` takes the 96-bit magnitude as three 32-bit words
+        let magnitude = mantissa.unsigned_abs();
+        let [lo, mid, hi] = [0, 32, 64]
+            .map(|shift| proc_macro2::Literal::u32_unsuffixed((magnitude >> shift) as u32));
+        let negative = mantissa < 0;
+        let scale = proc_macro2::Literal::u32_unsuffixed(scale);
+        Ok(quote! {
+            {
+                const BOUND: #ty = <#ty>::from_parts(#lo, #mid, #hi, #negative, #scale);
+                BOUND
+            }
+        })
+    }
+
+    /// Expression building the `borang::RangeBound` reported for a field
+    ///
+    /// Literal bounds of decimal fields are reported as the exact `Decimal`,
+    /// or as `f64` if they don't fit one, which `to_decimal_tokens` rejects.
+    fn to_error_tokens_for(&self, ty: &syn::Type) -> proc_macro2::TokenStream {
+        if is_decimal_type(ty) {
+            if let Ok(decimal) = self.to_decimal_tokens(ty) {
+                return quote! { borang::RangeBound::from(#decimal) };
+            }
+        }
+        self.to_error_tokens()
+    }
+
+    /// Expression building the `borang::RangeBound` reported in errors
+    fn to_error_tokens(&self) -> proc_macro2::TokenStream {
+        match *self {
+            RangeBound::Int(v) => quote! { borang::RangeBound::Int(#v) },
+            RangeBound::UInt(v) => quote! { borang::RangeBound::UInt(#v) },
+            RangeBound::Float(v, _) => {
+                let lit = proc_macro2::Literal::f64_unsuffixed(v);
+                quote! { borang::RangeBound::Float(#lit) }
+            }
+            RangeBound::Expr(ref expr) => quote! { borang::RangeBound::from(#expr) },
+        }
+    }
+}
+
+/// Get `T` if a field type is `Option<T>`
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Wrap validation code so it only runs when an optional field is `Some`
+fn skip_none(
+    field_ident: &syn::Ident,
+    optional: bool,
+    body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if optional {
+        quote! {
+            if let Some(field_value) = &self.#field_ident {
+                #body
+            }
+        }
+    } else {
+        body
+    }
+}
+
+/// A date bound for the date validator
+#[derive(Debug, Clone)]
+enum DateBound {
+    /// A fixed ISO 8601 date like `2020-01-01`
+    Fixed(String),
+    /// The current date at validation time
+    Today,
+}
+
+impl DateBound {
+    /// Parse a fixed date bound, checking the `YYYY-MM-DD` format at compile time
+    fn from_lit(name: &str, value: &syn::Lit) -> syn::Result<Self> {
+        let syn::Lit::Str(lit_str) = value else {
+            return Err(syn::Error::new_spanned(
+                value,
+                format!(
+                    "{} parameter must be a date string (e.g., \"2020-01-01\")",
+                    name
+                ),
+            ));
+        };
+        let date = lit_str.value();
+        let date_pattern = regex::Regex::new(r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])$")
+            .expect("date pattern is valid");
+        if !date_pattern.is_match(&date) {
+            return Err(syn::Error::new_spanned(
+                lit_str,
+                format!("{} must be a date in YYYY-MM-DD format", name),
+            ));
+        }
+        Ok(DateBound::Fixed(date))
+    }
+
+    /// Expression producing the bound as a `String` at runtime
+    fn to_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            DateBound::Fixed(date) => quote! { #date.to_string() },
+            DateBound::Today => quote! { borang::validation::today() },
+        }
+    }
+}
+
+/// Parse a boolean flag parameter like `after_today` or `after_today = true`
+fn parse_bool_param(name: &str, value: &syn::Lit) -> syn::Result<bool> {
+    if let syn::Lit::Bool(lit_bool) = value {
+        Ok(lit_bool.value)
+    } else {
+        Err(syn::Error::new_spanned(
+            value,
+            format!("{} parameter must be a boolean", name),
+        ))
+    }
+}
+
+/// Check whether a field type is a floating-point primitive
+fn is_float_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "f32" || segment.ident == "f64"),
+        _ => false,
+    }
+}
+
+/// Check whether a field type is an integer primitive or non-zero integer
+fn is_integer_type(ty: &syn::Type) -> bool {
+    const INTEGERS: &[&str] = &[
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| INTEGERS.iter().any(|integer| segment.ident == integer)),
+        _ => false,
+    }
+}
+
+/// Check whether a type is one of the `std::num` non-zero integers
+fn is_non_zero_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident.to_string().starts_with("NonZero")),
+        _ => false,
+    }
+}
+
+/// Check whether a field type is `rust_decimal::Decimal`
+fn is_decimal_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Decimal"),
+        _ => false,
+    }
+}
+
+/// A transformation applied to the raw string value before parsing
+#[derive(Debug, Clone, Copy)]
+enum Sanitizer {
+    Trim,
+    TrimStart,
+    TrimEnd,
+    Lowercase,
+    Uppercase,
+}
+
+impl Sanitizer {
+    /// Generate code that rebinds `value` to the sanitized string
+    fn to_tokens(self) -> proc_macro2::TokenStream {
+        match self {
+            Sanitizer::Trim => quote! { let value = value.trim().to_string(); },
+            Sanitizer::TrimStart => quote! { let value = value.trim_start().to_string(); },
+            Sanitizer::TrimEnd => quote! { let value = value.trim_end().to_string(); },
+            Sanitizer::Lowercase => quote! { let value = value.to_lowercase(); },
+            Sanitizer::Uppercase => quote! { let value = value.to_uppercase(); },
+        }
+    }
+}
+
+/// Options accepted by every built-in validator
+#[derive(Debug, Clone, Default)]
+struct ValidatorOptions {
+    /// Replaces the default error message, e.g. `required(message = "...")`
+    message: Option<String>,
+    /// Machine-readable i18n key, e.g. `email(message_key = "errors.email")`
+    message_key: Option<String>,
+    /// Report failures as warnings that don't block submission,
+    /// e.g. `length(max = 200, severity = "warning")`
+    warning: bool,
+    /// Only compile the validator when this predicate holds,
+    /// e.g. `length(min = 12, cfg = "not(debug_assertions)")`
+    cfg: Option<proc_macro2::TokenStream>,
+    /// Where the validator is named in the attribute, for diagnostics
+    span: Option<proc_macro2::Span>,
+}
+
+impl ValidatorOptions {
+    /// Parse a `message = "..."` parameter
+    fn set_message(&mut self, value: syn::Lit) -> syn::Result<()> {
+        if let syn::Lit::Str(lit_str) = value {
+            self.message = Some(lit_str.value());
+            Ok(())
+        } else {
+            Err(syn::Error::new_spanned(
+                value,
+                "message parameter must be a string literal",
+            ))
+        }
+    }
+
+    /// Parse a `message_key = "..."` parameter
+    fn set_message_key(&mut self, value: syn::Lit) -> syn::Result<()> {
+        if let syn::Lit::Str(lit_str) = value {
+            self.message_key = Some(lit_str.value());
+            Ok(())
+        } else {
+            Err(syn::Error::new_spanned(
+                value,
+                "message_key parameter must be a string literal",
+            ))
+        }
+    }
+
+    /// Parse a `severity = "error" | "warning"` parameter
+    fn set_severity(&mut self, value: syn::Lit) -> syn::Result<()> {
+        match &value {
+            syn::Lit::Str(lit_str) => match lit_str.value().as_str() {
+                "error" => self.warning = false,
+                "warning" => self.warning = true,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "severity must be \"error\" or \"warning\"",
+                    ))
+                }
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    value,
+                    "severity parameter must be a string literal",
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse a `cfg = "..."` parameter, checking it is a valid predicate
+    fn set_cfg(&mut self, value: syn::Lit) -> syn::Result<()> {
+        if let syn::Lit::Str(lit_str) = value {
+            let predicate = lit_str.parse::<Meta>()?;
+            self.cfg = Some(quote! { #predicate });
+            Ok(())
+        } else {
+            Err(syn::Error::new_spanned(
+                value,
+                "cfg parameter must be a string literal",
+            ))
+        }
+    }
+
+    /// Parse a shared option parameter, returning false if `name` isn't one
+    fn set_option(&mut self, name: &str, value: syn::Lit) -> syn::Result<bool> {
+        match name {
+            "message" => self.set_message(value)?,
+            "message_key" => self.set_message_key(value)?,
+            "severity" => self.set_severity(value)?,
+            "cfg" => self.set_cfg(value)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Method calls applying the message and message key overrides
+    fn override_tokens(&self) -> proc_macro2::TokenStream {
+        let message = self
+            .message
+            .as_ref()
+            .map(|message| quote! { .with_message(#message) });
+        let message_key = self
+            .message_key
+            .as_ref()
+            .map(|key| quote! { .with_message_key(#key) });
+        quote! { #message #message_key }
+    }
+
+    /// Wrap generated validation code in the `cfg` predicate, if any
+    fn wrap_cfg(&self, code: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match &self.cfg {
+            Some(predicate) => quote! {
+                #[cfg(#predicate)]
+                {
+                    #code
+                }
+            },
+            None => code,
+        }
+    }
 }
 
 /// Represents all validation rules for a single field
 struct FieldValidation {
     field_name: String,
+    /// The field's own ident, keeping its span for diagnostics
+    field_ident: syn::Ident,
     field_type: syn::Type,
-    validators: Vec<Validator>,
+    validators: Vec<(Validator, ValidatorOptions)>,
+    sanitizers: Vec<Sanitizer>,
+    attrs: FieldAttrs,
+}
+
+/// Field options from `#[field(...)]`
+#[derive(Default)]
+struct FieldAttrs {
+    /// Human-friendly name used in default messages, e.g. `label = "Email address"`
+    label: Option<String>,
+    /// Input mask the value is shown with, e.g. `mask = "##-###"`
+    mask: Option<syn::LitStr>,
+    /// String key used instead of the Rust field name, e.g. `rename = "emailAddress"`
+    rename: Option<syn::LitStr>,
+    /// Keys of the fields whose changes revalidate this one, e.g. `depends_on = "password"`
+    depends_on: Vec<syn::LitStr>,
+    /// The field is a `Vec` of items with fields of their own, from `array`
+    array: bool,
+    /// The field is a struct with fields of their own, from `nested`
+    nested: bool,
 }
 
 /// Parse validator parameters like `min = 8, max = 100`
+///
+/// A bare flag such as `after_today` is treated as `after_today = true`.
+///
+/// Numeric parameters may also be constants or expressions such as
+/// `min = PASSWORD_MIN`, which are collected in `exprs` and emitted verbatim.
 struct ValidatorParams {
     params: Vec<(String, syn::Lit)>,
+    exprs: Vec<(String, proc_macro2::TokenStream)>,
 }
 
-impl Parse for ValidatorParams {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
+impl ValidatorParams {
+    /// Parse parameters, allowing non-literal values for the names in `expr_params`
+    fn parse_with_exprs(
+        tokens: proc_macro2::TokenStream,
+        expr_params: &[&str],
+    ) -> syn::Result<Self> {
+        syn::parse::Parser::parse2(
+            |input: ParseStream| Self::parse_inner(input, expr_params),
+            tokens,
+        )
+    }
+
+    fn parse_inner(input: ParseStream, expr_params: &[&str]) -> syn::Result<Self> {
         let mut params = Vec::new();
+        let mut exprs = Vec::new();
 
         while !input.is_empty() {
             let name: syn::Ident = input.parse()?;
-            input.parse::<Token![=]>()?;
-            let value: syn::Lit = input.parse()?;
-            params.push((name.to_string(), value));
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                let value: Expr = input.parse()?;
+                match literal_value(&value) {
+                    Some(lit) => params.push((name.to_string(), lit)),
+                    None if expr_params.contains(&name.to_string().as_str()) => {
+                        exprs.push((name.to_string(), quote! { #value }));
+                    }
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            format!("{} parameter must be a literal", name),
+                        ));
+                    }
+                }
+            } else {
+                params.push((
+                    name.to_string(),
+                    syn::Lit::Bool(syn::LitBool::new(true, name.span())),
+                ));
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(ValidatorParams { params, exprs })
+    }
+}
+
+impl Parse for ValidatorParams {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_inner(input, &[])
+    }
+}
+
+/// Get the literal from a literal expression, including negative numbers
+fn literal_value(expr: &Expr) -> Option<syn::Lit> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => Some(lit.clone()),
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) if matches!(
+            inner.as_ref(),
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            })
+        ) =>
+        {
+            syn::parse2(quote! { #expr }).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Parse `one_of` arguments: string literals optionally followed by options
+///
+/// e.g. `one_of("draft", "published", message = "Pick a status")`
+struct OneOfParams {
+    allowed: Vec<String>,
+    options: Vec<(String, syn::Lit)>,
+}
+
+impl Parse for OneOfParams {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut allowed = Vec::new();
+        let mut options = Vec::new();
+
+        while !input.is_empty() {
+            if input.peek(syn::LitStr) {
+                let value: syn::LitStr = input.parse()?;
+                allowed.push(value.value());
+            } else {
+                let name: syn::Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                let value: syn::Lit = input.parse()?;
+                options.push((name.to_string(), value));
+            }
 
             if input.peek(Token![,]) {
                 input.parse::<Token![,]>()?;
             }
         }
 
-        Ok(ValidatorParams { params })
+        Ok(OneOfParams { allowed, options })
     }
 }
 
 /// Parse a single validator from attribute content
-fn parse_validator(meta: &Meta) -> syn::Result<Validator> {
+///
+/// Parameters shared by all validators (like `message`) are stored in `options`.
+fn parse_validator(meta: &Meta, options: &mut ValidatorOptions) -> syn::Result<Validator> {
     match meta {
         // Simple validators: #[validator(required)]
         Meta::Path(path) => {
@@ -65,11 +761,36 @@ fn parse_validator(meta: &Meta) -> syn::Result<Validator> {
 
             match ident.to_string().as_str() {
                 "required" => Ok(Validator::Required),
-                "email" => Ok(Validator::Email),
+                "email" => Ok(Validator::Email {
+                    mode: EmailMode::default(),
+                }),
+                "uuid" => Ok(Validator::Uuid),
+                "hex_color" => Ok(Validator::HexColor),
+                "positive" => Ok(Validator::Sign {
+                    sign: SignConstraint::Positive,
+                }),
+                "non_negative" => Ok(Validator::Sign {
+                    sign: SignConstraint::NonNegative,
+                }),
+                "non_zero" => Ok(Validator::Sign {
+                    sign: SignConstraint::NonZero,
+                }),
+                "password" => Ok(Validator::Password {
+                    min_len: quote! { #DEFAULT_PASSWORD_MIN_LEN },
+                    min_classes: quote! { #DEFAULT_PASSWORD_MIN_CLASSES },
+                }),
+                "date" => Ok(Validator::Date {
+                    after: None,
+                    before: None,
+                }),
+                "file" => Ok(Validator::File {
+                    max_size: None,
+                    accept: None,
+                }),
                 name => Err(syn::Error::new_spanned(
                     ident,
                     format!(
-                        "Unknown validator: '{}'. Valid validators are: required, email, length, range, custom",
+                        "Unknown validator: '{}'. Valid validators are: required, email, uuid, hex_color, date, file, length, items, range, multiple_of, positive, non_negative, non_zero, one_of, password, custom, custom_value, rule, registered, any, pattern, skip",
                         name
                     ),
                 )),
@@ -85,48 +806,21 @@ fn parse_validator(meta: &Meta) -> syn::Result<Validator> {
                 .to_string();
 
             match validator_name.as_str() {
-                "length" => {
+                "email" => {
                     let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
-                    let mut min = None;
-                    let mut max = None;
+                    let mut mode = EmailMode::default();
 
                     for (name, value) in params.params {
                         match name.as_str() {
-                            "min" => {
-                                if let syn::Lit::Int(lit_int) = value {
-                                    min = Some(lit_int.base10_parse::<usize>().map_err(|e| {
-                                        syn::Error::new_spanned(
-                                            lit_int,
-                                            format!("Invalid min value: {}", e),
-                                        )
-                                    })?);
-                                } else {
-                                    return Err(syn::Error::new_spanned(
-                                        value,
-                                        "min parameter must be an integer",
-                                    ));
-                                }
-                            }
-                            "max" => {
-                                if let syn::Lit::Int(lit_int) = value {
-                                    max = Some(lit_int.base10_parse::<usize>().map_err(|e| {
-                                        syn::Error::new_spanned(
-                                            lit_int,
-                                            format!("Invalid max value: {}", e),
-                                        )
-                                    })?);
-                                } else {
-                                    return Err(syn::Error::new_spanned(
-                                        value,
-                                        "max parameter must be an integer",
-                                    ));
-                                }
+                            "mode" => mode = EmailMode::from_lit(&value)?,
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
                             }
                             _ => {
                                 return Err(syn::Error::new_spanned(
                                     &list.tokens,
                                     format!(
-                                        "Unknown parameter '{}' for length validator. Valid parameters: min, max",
+                                        "Unknown parameter '{}' for email validator. Valid parameters: mode, message, message_key, severity, cfg",
                                         name
                                     ),
                                 ));
@@ -134,58 +828,65 @@ fn parse_validator(meta: &Meta) -> syn::Result<Validator> {
                         }
                     }
 
-                    if min.is_none() && max.is_none() {
-                        return Err(syn::Error::new_spanned(
-                            &list.tokens,
-                            "length validator requires at least one of: min, max",
-                        ));
+                    Ok(Validator::Email { mode })
+                }
+
+                "required" | "uuid" | "hex_color" | "positive" | "non_negative" | "non_zero" => {
+                    let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
+
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for {} validator. Valid parameters: message, message_key, severity, cfg",
+                                        name, validator_name
+                                    ),
+                                ));
+                            }
+                        }
                     }
 
-                    Ok(Validator::Length { min, max })
+                    // Only options were given, so parse it like the bare form
+                    parse_validator(&Meta::Path(list.path.clone()), options)
                 }
 
-                "range" => {
-                    let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
+                "length" => {
+                    let params = ValidatorParams::parse_with_exprs(
+                        list.tokens.clone(),
+                        &["min", "max", "equal"],
+                    )?;
                     let mut min = None;
                     let mut max = None;
+                    let mut equal = None;
+                    let mut bytes = false;
+
+                    for (name, expr) in params.exprs {
+                        match name.as_str() {
+                            "min" => min = Some(quote! { (#expr) }),
+                            "max" => max = Some(quote! { (#expr) }),
+                            _ => equal = Some(quote! { (#expr) }),
+                        }
+                    }
 
                     for (name, value) in params.params {
                         match name.as_str() {
-                            "min" => {
-                                if let syn::Lit::Int(lit_int) = value {
-                                    min = Some(lit_int.base10_parse::<i64>().map_err(|e| {
-                                        syn::Error::new_spanned(
-                                            lit_int,
-                                            format!("Invalid min value: {}", e),
-                                        )
-                                    })?);
-                                } else {
-                                    return Err(syn::Error::new_spanned(
-                                        value,
-                                        "min parameter must be an integer",
-                                    ));
-                                }
-                            }
-                            "max" => {
-                                if let syn::Lit::Int(lit_int) = value {
-                                    max = Some(lit_int.base10_parse::<i64>().map_err(|e| {
-                                        syn::Error::new_spanned(
-                                            lit_int,
-                                            format!("Invalid max value: {}", e),
-                                        )
-                                    })?);
-                                } else {
-                                    return Err(syn::Error::new_spanned(
-                                        value,
-                                        "max parameter must be an integer",
-                                    ));
-                                }
+                            "min" => min = Some(parse_usize_param("min", &value)?),
+                            "max" => max = Some(parse_usize_param("max", &value)?),
+                            "equal" => equal = Some(parse_usize_param("equal", &value)?),
+                            "bytes" => bytes = parse_bool_param("bytes", &value)?,
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
                             }
                             _ => {
                                 return Err(syn::Error::new_spanned(
                                     &list.tokens,
                                     format!(
-                                        "Unknown parameter '{}' for range validator. Valid parameters: min, max",
+                                        "Unknown parameter '{}' for length validator. Valid parameters: min, max, equal, bytes, message, message_key, severity, cfg",
                                         name
                                     ),
                                 ));
@@ -193,144 +894,1146 @@ fn parse_validator(meta: &Meta) -> syn::Result<Validator> {
                         }
                     }
 
-                    if min.is_none() && max.is_none() {
+                    if min.is_none() && max.is_none() && equal.is_none() {
                         return Err(syn::Error::new_spanned(
                             &list.tokens,
-                            "range validator requires at least one of: min, max",
+                            "length validator requires at least one of: min, max, equal",
                         ));
                     }
 
-                    Ok(Validator::Range { min, max })
-                }
-
-                "custom" => {
-                    let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
-
-                    if params.params.len() != 1 {
+                    if equal.is_some() && (min.is_some() || max.is_some()) {
                         return Err(syn::Error::new_spanned(
                             &list.tokens,
-                            "custom validator requires exactly one parameter: the method name as a string",
+                            "length validator parameter equal cannot be combined with min or max",
                         ));
                     }
 
-                    let (param_name, value) = &params.params[0];
-                    if param_name != "method" && params.params.len() == 1 {
-                        // Allow unnamed parameter for custom
-                        if let syn::Lit::Str(lit_str) = value {
-                            return Ok(Validator::Custom {
-                                method_name: lit_str.value(),
-                            });
+                    Ok(Validator::Length {
+                        min,
+                        max,
+                        equal,
+                        bytes,
+                    })
+                }
+
+                "items" => {
+                    let params =
+                        ValidatorParams::parse_with_exprs(list.tokens.clone(), &["min", "max"])?;
+                    let mut min = None;
+                    let mut max = None;
+
+                    for (name, expr) in params.exprs {
+                        match name.as_str() {
+                            "min" => min = Some(quote! { (#expr) }),
+                            _ => max = Some(quote! { (#expr) }),
                         }
                     }
 
-                    Err(syn::Error::new_spanned(
-                        &list.tokens,
-                        "custom validator parameter must be a string literal (e.g., custom(\"method_name\"))",
-                    ))
-                }
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "min" => min = Some(parse_usize_param("min", &value)?),
+                            "max" => max = Some(parse_usize_param("max", &value)?),
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for items validator. Valid parameters: min, max, message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    if min.is_none() && max.is_none() {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "items validator requires at least one of: min, max",
+                        ));
+                    }
+
+                    Ok(Validator::Items { min, max })
+                }
+
+                "password" => {
+                    let params = ValidatorParams::parse_with_exprs(
+                        list.tokens.clone(),
+                        &["min_len", "min_classes"],
+                    )?;
+                    let mut min_len = quote! { #DEFAULT_PASSWORD_MIN_LEN };
+                    let mut min_classes = quote! { #DEFAULT_PASSWORD_MIN_CLASSES };
+
+                    for (name, expr) in params.exprs {
+                        match name.as_str() {
+                            "min_len" => min_len = quote! { (#expr) },
+                            _ => min_classes = quote! { (#expr) },
+                        }
+                    }
+
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "min_len" => min_len = parse_usize_param("min_len", &value)?,
+                            "min_classes" => {
+                                min_classes = parse_usize_param("min_classes", &value)?;
+                                if matches!(&value, syn::Lit::Int(lit_int) if lit_int.base10_parse::<usize>().is_ok_and(|v| v > 4)) {
+                                    return Err(syn::Error::new_spanned(
+                                        value,
+                                        "min_classes must be at most 4 (lowercase, uppercase, digit, symbol)",
+                                    ));
+                                }
+                            }
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for password validator. Valid parameters: min_len, min_classes, message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    Ok(Validator::Password {
+                        min_len,
+                        min_classes,
+                    })
+                }
+
+                "one_of" => {
+                    let params: OneOfParams = syn::parse2(list.tokens.clone())?;
+
+                    for (name, value) in params.options {
+                        match name.as_str() {
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for one_of validator. Valid parameters: message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    if params.allowed.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "one_of validator requires at least one allowed value (e.g., one_of(\"draft\", \"published\"))",
+                        ));
+                    }
+
+                    Ok(Validator::OneOf {
+                        allowed: params.allowed,
+                    })
+                }
+
+                "date" => {
+                    let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
+                    let mut after = None;
+                    let mut before = None;
+
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "after" => after = Some(DateBound::from_lit("after", &value)?),
+                            "before" => before = Some(DateBound::from_lit("before", &value)?),
+                            "after_today" => {
+                                if parse_bool_param("after_today", &value)? {
+                                    after = Some(DateBound::Today);
+                                }
+                            }
+                            "before_today" => {
+                                if parse_bool_param("before_today", &value)? {
+                                    before = Some(DateBound::Today);
+                                }
+                            }
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for date validator. Valid parameters: after, before, after_today, before_today, message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    Ok(Validator::Date { after, before })
+                }
+
+                "file" => {
+                    let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
+                    let mut max_size = None;
+                    let mut accept = None;
+
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "max_size_mb" => {
+                                let megabytes = match RangeBound::from_lit("max_size_mb", &value)? {
+                                    RangeBound::Int(v) if v > 0 => v as f64,
+                                    RangeBound::UInt(v) => v as f64,
+                                    RangeBound::Float(v, _) if v > 0.0 => v,
+                                    _ => {
+                                        return Err(syn::Error::new_spanned(
+                                            value,
+                                            "max_size_mb must be greater than zero",
+                                        ));
+                                    }
+                                };
+                                max_size = Some((megabytes * 1024.0 * 1024.0) as u64);
+                            }
+                            "accept" => {
+                                if let syn::Lit::Str(lit_str) = value {
+                                    accept = Some(lit_str.value());
+                                } else {
+                                    return Err(syn::Error::new_spanned(
+                                        value,
+                                        "accept parameter must be a string literal",
+                                    ));
+                                }
+                            }
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for file validator. Valid parameters: max_size_mb, accept, message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    if max_size.is_none() && accept.is_none() {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "file validator requires at least one of: max_size_mb, accept",
+                        ));
+                    }
+
+                    Ok(Validator::File { max_size, accept })
+                }
+
+                "range" => {
+                    let params =
+                        ValidatorParams::parse_with_exprs(list.tokens.clone(), &["min", "max"])?;
+                    let mut min = None;
+                    let mut max = None;
+
+                    for (name, expr) in params.exprs {
+                        match name.as_str() {
+                            "min" => min = Some(RangeBound::Expr(expr)),
+                            _ => max = Some(RangeBound::Expr(expr)),
+                        }
+                    }
+
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "min" => min = Some(RangeBound::from_lit("min", &value)?),
+                            "max" => max = Some(RangeBound::from_lit("max", &value)?),
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for range validator. Valid parameters: min, max, message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    if min.is_none() && max.is_none() {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "range validator requires at least one of: min, max",
+                        ));
+                    }
+
+                    Ok(Validator::Range { min, max })
+                }
+
+                "pattern" => {
+                    let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
+                    let mut pattern = None;
+
+                    for (name, value) in params.params {
+                        match name.as_str() {
+                            "regex" => {
+                                if let syn::Lit::Str(lit_str) = value {
+                                    pattern = Some(parse_pattern(&lit_str)?);
+                                } else {
+                                    return Err(syn::Error::new_spanned(
+                                        value,
+                                        "regex parameter must be a string literal",
+                                    ));
+                                }
+                            }
+                            "message" | "message_key" | "severity" | "cfg" => {
+                                options.set_option(&name, value)?;
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    &list.tokens,
+                                    format!(
+                                        "Unknown parameter '{}' for pattern validator. Valid parameters: regex, message, message_key, severity, cfg",
+                                        name
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    let pattern = pattern.ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            &list.tokens,
+                            "pattern validator requires a regex parameter",
+                        )
+                    })?;
+
+                    Ok(Validator::Pattern { pattern })
+                }
+
+                "custom" => {
+                    // custom("method_name") or custom(method = "method_name")
+                    let lit_str = match syn::parse2::<syn::LitStr>(list.tokens.clone()) {
+                        Ok(lit_str) => lit_str,
+                        Err(_) => {
+                            let params: ValidatorParams = syn::parse2(list.tokens.clone())?;
+                            match params.params.as_slice() {
+                                [(name, syn::Lit::Str(lit_str))] if name == "method" => {
+                                    lit_str.clone()
+                                }
+                                _ => {
+                                    return Err(syn::Error::new_spanned(
+                                        &list.tokens,
+                                        "custom validator parameter must be a string literal (e.g., custom(\"method_name\"))",
+                                    ));
+                                }
+                            }
+                        }
+                    };
+
+                    Ok(Validator::Custom {
+                        method: lit_str.parse()?,
+                    })
+                }
+
+                "any" => {
+                    // any(email, pattern = "^\\+?[0-9]+$") passes if one validator passes
+                    let mut validators = Vec::new();
+                    let mut skip = false;
+                    syn::meta::parser(|meta| parse_validator_item(meta, &mut validators, &mut skip))
+                        .parse2(list.tokens.clone())?;
+
+                    if skip {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "skip is not allowed inside any(...)",
+                        ));
+                    }
+                    if validators.len() < 2 {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "any validator requires at least two validators (e.g., any(email, pattern = \"^[0-9]+$\"))",
+                        ));
+                    }
+                    if validators.iter().any(|(_, options)| options.warning) {
+                        return Err(syn::Error::new_spanned(
+                            &list.tokens,
+                            "severity is not allowed inside any(...), set it on any itself",
+                        ));
+                    }
+
+                    Ok(Validator::Any { validators })
+                }
+
+                name => Err(syn::Error::new_spanned(
+                    &list.path,
+                    format!(
+                        "Unknown validator: '{}'. Valid validators are: required, email, uuid, hex_color, date, file, length, items, range, multiple_of, positive, non_negative, non_zero, one_of, password, custom, custom_value, rule, registered, any, pattern, skip",
+                        name
+                    ),
+                )),
+            }
+        }
+
+        // Name-value validators: #[validator(custom = "method_name")]
+        Meta::NameValue(nv) => {
+            let validator_name = nv
+                .path
+                .get_ident()
+                .ok_or_else(|| syn::Error::new_spanned(&nv.path, "Expected validator name"))?
+                .to_string();
+
+            match validator_name.as_str() {
+                "custom" => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    {
+                        Ok(Validator::Custom {
+                            method: lit_str.parse()?,
+                        })
+                    } else {
+                        Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "custom validator value must be a string literal",
+                        ))
+                    }
+                }
+                "custom_value" => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    {
+                        // A bare name refers to an associated function, a path to a free function
+                        let function = lit_str.parse::<syn::Path>()?;
+                        let span = lit_str.span();
+                        let function = if function.get_ident().is_some() {
+                            quote_spanned! {span=> Self::#function }
+                        } else {
+                            quote! { #function }
+                        };
+                        Ok(Validator::CustomValue { function, span })
+                    } else {
+                        Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "custom_value validator value must be a string literal",
+                        ))
+                    }
+                }
+                "rule" => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    {
+                        let rule = lit_str.parse::<Expr>()?;
+                        Ok(Validator::Rule {
+                            rule: quote! { #rule },
+                            span: lit_str.span(),
+                        })
+                    } else {
+                        Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "rule validator value must be a string literal",
+                        ))
+                    }
+                }
+                "registered" => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    {
+                        Ok(Validator::Registered {
+                            name: lit_str.value(),
+                        })
+                    } else {
+                        Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "registered validator value must be a string literal",
+                        ))
+                    }
+                }
+                "multiple_of" => {
+                    let step = match literal_value(&nv.value) {
+                        Some(lit) => RangeBound::from_lit("multiple_of", &lit)?,
+                        None => {
+                            let expr = &nv.value;
+                            RangeBound::Expr(quote! { #expr })
+                        }
+                    };
+                    if step.is_zero() {
+                        return Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "multiple_of value must not be zero",
+                        ));
+                    }
+                    Ok(Validator::MultipleOf { step })
+                }
+                "pattern" => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    {
+                        Ok(Validator::Pattern {
+                            pattern: parse_pattern(lit_str)?,
+                        })
+                    } else {
+                        Err(syn::Error::new_spanned(
+                            &nv.value,
+                            "pattern validator value must be a string literal",
+                        ))
+                    }
+                }
+                name => Err(syn::Error::new_spanned(
+                    &nv.path,
+                    format!("Validator '{}' does not support name-value syntax", name),
+                )),
+            }
+        }
+    }
+}
+
+/// Parse a non-negative integer parameter like `min = 1` into a `usize` literal
+fn parse_usize_param(name: &str, value: &syn::Lit) -> syn::Result<proc_macro2::TokenStream> {
+    if let syn::Lit::Int(lit_int) = value {
+        let parsed = lit_int.base10_parse::<usize>().map_err(|e| {
+            syn::Error::new_spanned(lit_int, format!("Invalid {} value: {}", name, e))
+        })?;
+        Ok(quote! { #parsed })
+    } else {
+        Err(syn::Error::new_spanned(
+            value,
+            format!("{} parameter must be an integer", name),
+        ))
+    }
+}
+
+/// Check that a pattern is a valid regex at compile time
+fn parse_pattern(lit_str: &syn::LitStr) -> syn::Result<String> {
+    let pattern = lit_str.value();
+    regex::Regex::new(&pattern)
+        .map_err(|e| syn::Error::new_spanned(lit_str, format!("Invalid pattern: {}", e)))?;
+    Ok(pattern)
+}
+
+/// Format a type as written in the struct, e.g. `Option<u32>`
+fn type_name(ty: &syn::Type) -> String {
+    let mut name = quote! { #ty }.to_string();
+    for (spaced, tight) in [
+        (" :: ", "::"),
+        (" < ", "<"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("& ", "&"),
+    ] {
+        name = name.replace(spaced, tight);
+    }
+    name
+}
+
+/// Get a validator's name and its `borang::ValidatorMeta` parameters, as
+/// `(name, value)` pairs with the value converted to a `String`
+fn validator_meta(validator: &Validator) -> (&'static str, Vec<proc_macro2::TokenStream>) {
+    let param = |name: &str, value: proc_macro2::TokenStream| {
+        quote! { (#name, (#value).to_string()) }
+    };
+    let optional =
+        |name: &str, value: Option<proc_macro2::TokenStream>| value.map(|value| param(name, value));
+
+    match validator {
+        Validator::Required => ("required", Vec::new()),
+        Validator::Email { mode } => {
+            let mode = match mode {
+                EmailMode::Simple => "simple",
+                EmailMode::Html5 => "html5",
+                EmailMode::Rfc5322 => "rfc5322",
+            };
+            ("email", vec![param("mode", quote! { #mode })])
+        }
+        Validator::Uuid => ("uuid", Vec::new()),
+        Validator::HexColor => ("hex_color", Vec::new()),
+        Validator::Length {
+            min,
+            max,
+            equal,
+            bytes,
+        } => {
+            let mut params: Vec<_> = [("min", min), ("max", max), ("equal", equal)]
+                .into_iter()
+                .filter_map(|(name, value)| optional(name, value.clone()))
+                .collect();
+            if *bytes {
+                params.push(param("bytes", quote! { true }));
+            }
+            ("length", params)
+        }
+        Validator::Items { min, max } => (
+            "items",
+            [("min", min), ("max", max)]
+                .into_iter()
+                .filter_map(|(name, value)| optional(name, value.clone()))
+                .collect(),
+        ),
+        Validator::Date { after, before } => (
+            "date",
+            [("after", after), ("before", before)]
+                .into_iter()
+                .filter_map(|(name, bound)| match bound {
+                    Some(DateBound::Fixed(date)) => Some(param(name, quote! { #date })),
+                    Some(DateBound::Today) => {
+                        Some(param(&format!("{}_today", name), quote! { true }))
+                    }
+                    None => None,
+                })
+                .collect(),
+        ),
+        Validator::Range { min, max } => (
+            "range",
+            [("min", min), ("max", max)]
+                .into_iter()
+                .filter_map(|(name, bound)| {
+                    optional(name, bound.as_ref().map(RangeBound::to_error_tokens))
+                })
+                .collect(),
+        ),
+        Validator::Custom { method } => {
+            let method = method.to_string();
+            ("custom", vec![param("method", quote! { #method })])
+        }
+        Validator::CustomValue { function, .. } => {
+            let function = function.to_string().replace(' ', "");
+            (
+                "custom_value",
+                vec![param("function", quote! { #function })],
+            )
+        }
+        Validator::Rule { rule, .. } => {
+            let rule = rule.to_string();
+            ("rule", vec![param("rule", quote! { #rule })])
+        }
+        Validator::Any { validators } => (
+            "any",
+            validators
+                .iter()
+                .map(|(validator, _)| {
+                    let (name, _) = validator_meta(validator);
+                    param("validator", quote! { #name })
+                })
+                .collect(),
+        ),
+        Validator::Registered { name } => ("registered", vec![param("name", quote! { #name })]),
+        Validator::Pattern { pattern } => ("pattern", vec![param("regex", quote! { #pattern })]),
+        Validator::OneOf { allowed } => (
+            "one_of",
+            allowed
+                .iter()
+                .map(|value| param("allowed", quote! { #value }))
+                .collect(),
+        ),
+        Validator::Sign { sign } => match sign {
+            SignConstraint::Positive => ("positive", Vec::new()),
+            SignConstraint::NonNegative => ("non_negative", Vec::new()),
+            SignConstraint::NonZero => ("non_zero", Vec::new()),
+        },
+        Validator::MultipleOf { step } => {
+            ("multiple_of", vec![param("step", step.to_error_tokens())])
+        }
+        Validator::Password {
+            min_len,
+            min_classes,
+        } => (
+            "password",
+            vec![
+                param("min_len", min_len.clone()),
+                param("min_classes", min_classes.clone()),
+            ],
+        ),
+        Validator::File { max_size, accept } => {
+            let mut params = Vec::new();
+            if let Some(max_size) = max_size {
+                params.push(param("max_size", quote! { #max_size }));
+            }
+            if let Some(accept) = accept {
+                params.push(param("accept", quote! { #accept }));
+            }
+            ("file", params)
+        }
+    }
+}
+
+/// Rewrite a `pattern` regex for the HTML `pattern` attribute
+///
+/// Browsers match the attribute against the whole value, while the validator
+/// searches for a match anywhere, so unanchored patterns are padded with `.*`.
+/// Returns `None` for syntax JavaScript regexes don't share, like inline flags.
+fn html_pattern(pattern: &str) -> Option<String> {
+    let (js, alternation) = js_pattern(pattern)?;
+    let anchored = js.starts_with('^') && js.ends_with('$') && !js.ends_with("\\$");
+    if anchored && !alternation {
+        Some(js)
+    } else {
+        Some(format!(".*(?:{}).*", js))
+    }
+}
+
+/// Punctuation a JavaScript `v`-flag regex accepts escaped inside a class:
+/// its syntax characters, `/` and the class set punctuators
+const JS_CLASS_ESCAPES: &str = "^$\\.*+?()[]{}|/&-!#%,:;<=>@`~";
+
+/// Translate a Rust regex into one that compiles as a JavaScript regex with
+/// the `v` flag, which browsers use for the `pattern` attribute
+///
+/// Escapes JavaScript rejects are written as the plain character, and
+/// punctuation in classes is escaped, as `v` reserves most of it. Returns
+/// `None` for constructs JavaScript lacks or reads differently: inline flags,
+/// `\A` and `\z`, Unicode classes like `\pL`, POSIX and nested classes, class
+/// set operations and `\x{...}` escapes.
+///
+/// Also returns whether the pattern has a `|` outside its classes.
+fn js_pattern(pattern: &str) -> Option<(String, bool)> {
+    let mut js = String::with_capacity(pattern.len());
+    let mut alternation = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => js_escape(chars.next()?, chars.peek(), false, &mut js)?,
+            '(' if chars.peek() == Some(&'?') => {
+                chars.next();
+                match chars.next()? {
+                    ':' => js.push_str("(?:"),
+                    // Named groups, written `(?P<name>` or `(?<name>` in Rust
+                    'P' if chars.next()? == '<' => js.push_str("(?<"),
+                    '<' => js.push_str("(?<"),
+                    _ => return None,
+                }
+            }
+            '[' => {
+                js.push('[');
+                if chars.peek() == Some(&'^') {
+                    js.push(chars.next()?);
+                }
+                // A leading `]` is a literal in Rust but ends the class in
+                // JavaScript
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                    js.push_str("\\]");
+                }
+                // Whether a `-` now makes a range, which it can't right after
+                // another range or a class like `\d`
+                let mut after_atom = false;
+                let mut in_range = false;
+                loop {
+                    let c = chars.next()?;
+                    match c {
+                        ']' => break,
+                        '[' => return None,
+                        '&' | '-' | '~' if chars.peek() == Some(&c) => return None,
+                        '\\' => {
+                            let escaped = chars.next()?;
+                            js_escape(escaped, chars.peek(), true, &mut js)?;
+                            let single = !escaped.is_ascii_alphabetic()
+                                || matches!(escaped, 'n' | 'r' | 't' | 'f' | 'v' | 'x' | 'u');
+                            after_atom = single && !std::mem::take(&mut in_range);
+                            continue;
+                        }
+                        '-' if after_atom && chars.peek() != Some(&']') => {
+                            js.push('-');
+                            after_atom = false;
+                            in_range = true;
+                            continue;
+                        }
+                        c if JS_CLASS_ESCAPES.contains(c) => {
+                            js.push('\\');
+                            js.push(c);
+                        }
+                        c => js.push(c),
+                    }
+                    after_atom = !std::mem::take(&mut in_range);
+                }
+                js.push(']');
+            }
+            '|' => {
+                alternation = true;
+                js.push('|');
+            }
+            c => js.push(c),
+        }
+    }
+    Some((js, alternation))
+}
+
+/// Write the JavaScript form of the Rust escape `\escaped`, or return `None`
+/// if JavaScript has no equivalent
+fn js_escape(escaped: char, next: Option<&char>, in_class: bool, js: &mut String) -> Option<()> {
+    match escaped {
+        'd' | 'D' | 'w' | 'W' | 's' | 'S' | 'n' | 'r' | 't' | 'f' | 'v' | 'u' => {}
+        'b' | 'B' if !in_class && next != Some(&'{') => {}
+        'x' if next != Some(&'{') => {}
+        // Rust reads `\<` and `\>` as word boundaries
+        '<' | '>' if !in_class => return None,
+        c if c.is_ascii_punctuation() || c == ' ' => {
+            let special = if in_class {
+                JS_CLASS_ESCAPES.contains(c)
+            } else {
+                "^$\\.*+?()[]{}|/".contains(c)
+            };
+            if special {
+                js.push('\\');
+            }
+            js.push(c);
+            return Some(());
+        }
+        _ => return None,
+    }
+    js.push('\\');
+    js.push(escaped);
+    Some(())
+}
+
+/// Parse all validators from a field's attributes
+///
+/// Also returns whether the field is marked `#[validator(skip)]`.
+fn parse_field_validators(
+    field: &Field,
+) -> syn::Result<(Vec<(Validator, ValidatorOptions)>, bool)> {
+    let mut validators = Vec::new();
+    let mut skip = false;
+
+    for attr in &field.attrs {
+        // Only process #[validator(...)] attributes
+        if !attr.path().is_ident("validator") {
+            continue;
+        }
+
+        // Parse the attribute content
+        // parse_nested_meta calls the closure once for each comma-separated item
+        attr.parse_nested_meta(|meta| parse_validator_item(meta, &mut validators, &mut skip))?;
+    }
+
+    Ok((validators, skip))
+}
+
+/// Parse one comma-separated item of a validator list, e.g. `length(min = 8)`,
+/// `email` or an option like `message = "..."` for the previous validator
+fn parse_validator_item(
+    meta: syn::meta::ParseNestedMeta,
+    validators: &mut Vec<(Validator, ValidatorOptions)>,
+    skip: &mut bool,
+) -> syn::Result<()> {
+    // Convert ParseNestedMeta to Meta for parsing
+    let path = meta.path.clone();
+    let path_span = path.span();
+
+    // Check for parenthesized content (e.g., "length(min = 8)")
+    if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let tokens = content.parse::<proc_macro2::TokenStream>()?;
+
+        let mut options = ValidatorOptions::default();
+        let validator = parse_validator(
+            &Meta::List(syn::MetaList {
+                path,
+                delimiter: syn::MacroDelimiter::Paren(Default::default()),
+                tokens,
+            }),
+            &mut options,
+        )?;
+        options.span = Some(path_span);
+        validators.push((validator, options));
+        return Ok(());
+    }
+
+    // Check for options applying to the previous validator
+    // (e.g., "email, message_key = "errors.email"")
+    if let Some(name) = ["message", "message_key", "severity", "cfg"]
+        .into_iter()
+        .find(|name| path.is_ident(name))
+        .filter(|_| meta.input.peek(Token![=]))
+    {
+        meta.input.parse::<Token![=]>()?;
+        let value: syn::Lit = meta.input.parse()?;
+        let (_, options) = validators
+            .last_mut()
+            .ok_or_else(|| syn::Error::new_spanned(&path, "option must follow a validator"))?;
+        options.set_option(name, value)?;
+        return Ok(());
+    }
+
+    // Check for = value (e.g., "custom = "method_name"")
+    if meta.input.peek(Token![=]) {
+        meta.input.parse::<Token![=]>()?;
+        let value: Expr = meta.input.parse()?;
+
+        let mut options = ValidatorOptions::default();
+        let validator = parse_validator(
+            &Meta::NameValue(syn::MetaNameValue {
+                path,
+                eq_token: Default::default(),
+                value,
+            }),
+            &mut options,
+        )?;
+        options.span = Some(path_span);
+        validators.push((validator, options));
+        return Ok(());
+    }
+
+    // Disable all validation for the field
+    if path.is_ident("skip") {
+        *skip = true;
+        return Ok(());
+    }
+
+    // Otherwise it's a simple path (e.g., "required")
+    // This must come last because we need to check for other patterns first
+    let mut options = ValidatorOptions::default();
+    let validator = parse_validator(&Meta::Path(path), &mut options)?;
+    options.span = Some(path_span);
+    validators.push((validator, options));
+    Ok(())
+}
+
+/// Parse struct-level validator method names from `#[form_validator(...)]` attributes
+///
+/// The returned idents carry the span of their string literal, so errors about
+/// the method point at the attribute.
+fn extract_form_validators(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::Ident>> {
+    let mut methods = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident("form_validator") {
+            continue;
+        }
+
+        let names = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::LitStr, Token![,]>::parse_terminated,
+        )?;
+        if names.is_empty() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "form_validator requires at least one method name (e.g., form_validator(\"check_dates\"))",
+            ));
+        }
+        for name in names {
+            methods.push(name.parse()?);
+        }
+    }
+
+    Ok(methods)
+}
+
+/// Parse all sanitizers from a field's `#[sanitizer(...)]` attributes
+///
+/// Named `sanitizer` rather than `sanitize`, which is a built-in rustc attribute.
+fn parse_field_sanitizers(field: &Field) -> syn::Result<Vec<Sanitizer>> {
+    let mut sanitizers = Vec::new();
 
-                name => Err(syn::Error::new_spanned(
-                    &list.path,
-                    format!(
-                        "Unknown validator: '{}'. Valid validators are: required, email, length, range, custom",
-                        name
-                    ),
-                )),
-            }
+    for attr in &field.attrs {
+        if !attr.path().is_ident("sanitizer") {
+            continue;
         }
 
-        // Name-value validators: #[validator(custom = "method_name")]
-        Meta::NameValue(nv) => {
-            let validator_name = nv
+        attr.parse_nested_meta(|meta| {
+            let ident = meta
                 .path
                 .get_ident()
-                .ok_or_else(|| syn::Error::new_spanned(&nv.path, "Expected validator name"))?
-                .to_string();
+                .ok_or_else(|| meta.error("Expected sanitizer name"))?;
 
-            match validator_name.as_str() {
-                "custom" => {
-                    if let Expr::Lit(ExprLit {
-                        lit: Lit::Str(lit_str),
-                        ..
-                    }) = &nv.value
-                    {
-                        Ok(Validator::Custom {
-                            method_name: lit_str.value(),
-                        })
-                    } else {
-                        Err(syn::Error::new_spanned(
-                            &nv.value,
-                            "custom validator value must be a string literal",
-                        ))
-                    }
+            let sanitizer = match ident.to_string().as_str() {
+                "trim" => Sanitizer::Trim,
+                "trim_start" => Sanitizer::TrimStart,
+                "trim_end" => Sanitizer::TrimEnd,
+                "lowercase" => Sanitizer::Lowercase,
+                "uppercase" => Sanitizer::Uppercase,
+                name => {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        format!(
+                            "Unknown sanitizer: '{}'. Valid sanitizers are: trim, trim_start, trim_end, lowercase, uppercase",
+                            name
+                        ),
+                    ));
                 }
-                name => Err(syn::Error::new_spanned(
-                    &nv.path,
-                    format!("Validator '{}' does not support name-value syntax", name),
-                )),
+            };
+            sanitizers.push(sanitizer);
+            Ok(())
+        })?;
+    }
+
+    Ok(sanitizers)
+}
+
+/// Parse a field's `#[field(...)]` attributes
+fn parse_field_attrs(field: &Field) -> syn::Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("field") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("label") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                attrs.label = Some(value.value());
+                Ok(())
+            } else if meta.path.is_ident("mask") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if !value.value().contains(['#', 'A', '*']) {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "mask must contain at least one of the slots #, A or *",
+                    ));
+                }
+                attrs.mask = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if value.value().is_empty() {
+                    return Err(syn::Error::new_spanned(value, "rename must not be empty"));
+                }
+                attrs.rename = Some(value);
+                Ok(())
+            } else if meta.path.is_ident("depends_on") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                attrs.depends_on.push(value);
+                Ok(())
+            } else if meta.path.is_ident("array") {
+                attrs.array = true;
+                Ok(())
+            } else if meta.path.is_ident("nested") {
+                attrs.nested = true;
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "Unknown field option. Valid options: label, mask, rename, depends_on, array, nested",
+                ))
             }
+        })?;
+    }
+
+    if attrs.array && attrs.nested {
+        return Err(syn::Error::new_spanned(
+            &field.ident,
+            "a field cannot be both an array and nested",
+        ));
+    }
+
+    Ok(attrs)
+}
+
+/// Struct-level options from `#[form(...)]`
+#[derive(Default)]
+struct FormOptions {
+    /// Take field keys and skips from `#[serde(rename)]` and `#[serde(skip)]`
+    use_serde_names: bool,
+}
+
+/// Parse the struct's `#[form(...)]` attributes
+fn parse_form_options(attrs: &[syn::Attribute]) -> syn::Result<FormOptions> {
+    let mut options = FormOptions::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("form") {
+            continue;
         }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("use_serde_names") {
+                options.use_serde_names = true;
+                Ok(())
+            } else {
+                Err(meta.error("Unknown form option. Valid options: use_serde_names"))
+            }
+        })?;
     }
+
+    Ok(options)
 }
 
-/// Parse all validators from a field's attributes
-fn parse_field_validators(field: &Field) -> syn::Result<Vec<Validator>> {
-    let mut validators = Vec::new();
+/// Serde options of a field that affect its form key
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<syn::LitStr>,
+    skip: bool,
+}
+
+/// Read `#[serde(rename = "...")]` and `#[serde(skip)]` from a field
+///
+/// For `rename(serialize = "...", deserialize = "...")` the serialized name is
+/// used. Other serde options are ignored.
+fn parse_serde_attrs(field: &Field) -> syn::Result<SerdeAttrs> {
+    let mut serde = SerdeAttrs::default();
 
     for attr in &field.attrs {
-        // Only process #[validator(...)] attributes
-        if !attr.path().is_ident("validator") {
+        if !attr.path().is_ident("serde") {
             continue;
         }
 
-        // Parse the attribute content
-        // parse_nested_meta calls the closure once for each comma-separated item
         attr.parse_nested_meta(|meta| {
-            // Convert ParseNestedMeta to Meta for parsing
-            let path = meta.path.clone();
-
-            // Check for parenthesized content (e.g., "length(min = 8)")
-            if meta.input.peek(syn::token::Paren) {
+            if meta.path.is_ident("skip") {
+                serde.skip = true;
+            } else if meta.path.is_ident("rename") && meta.input.peek(Token![=]) {
+                serde.rename = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("rename") {
+                meta.parse_nested_meta(|inner| {
+                    let value: syn::LitStr = inner.value()?.parse()?;
+                    if inner.path.is_ident("serialize") {
+                        serde.rename = Some(value);
+                    }
+                    Ok(())
+                })?;
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
                 let content;
                 syn::parenthesized!(content in meta.input);
-                let tokens = content.parse::<proc_macro2::TokenStream>()?;
-
-                let validator = parse_validator(&Meta::List(syn::MetaList {
-                    path,
-                    delimiter: syn::MacroDelimiter::Paren(Default::default()),
-                    tokens,
-                }))?;
-                validators.push(validator);
-                return Ok(());
+                content.parse::<proc_macro2::TokenStream>()?;
             }
+            Ok(())
+        })?;
+    }
 
-            // Check for = value (e.g., "custom = "method_name"")
-            if meta.input.peek(Token![=]) {
-                meta.input.parse::<Token![=]>()?;
-                let value: Expr = meta.input.parse()?;
+    Ok(serde)
+}
 
-                let validator = parse_validator(&Meta::NameValue(syn::MetaNameValue {
-                    path,
-                    eq_token: Default::default(),
-                    value,
-                }))?;
-                validators.push(validator);
-                return Ok(());
-            }
+/// Check whether a field is marked `#[form(skip)]`
+fn is_form_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skip = false;
 
-            // Otherwise it's a simple path (e.g., "required")
-            // This must come last because we need to check for other patterns first
-            let validator = parse_validator(&Meta::Path(path))?;
-            validators.push(validator);
-            Ok(())
+    for attr in &field.attrs {
+        if !attr.path().is_ident("form") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("Unknown form option. Valid options: skip"))
+            }
         })?;
     }
 
-    Ok(validators)
+    Ok(skip)
 }
 
 /// Extract field validation information from struct fields
-fn extract_field_validations(data: &Data) -> syn::Result<Vec<FieldValidation>> {
+fn extract_field_validations(
+    data: &Data,
+    form_options: &FormOptions,
+) -> syn::Result<Vec<FieldValidation>> {
     let fields = match data {
         Data::Struct(data_struct) => match &data_struct.fields {
             Fields::Named(fields) => &fields.named,
@@ -356,30 +2059,161 @@ fn extract_field_validations(data: &Data) -> syn::Result<Vec<FieldValidation>> {
             .ident
             .as_ref()
             .ok_or_else(|| syn::Error::new_spanned(field, "Field must have a name"))?
+            .unraw()
             .to_string();
 
-        let validators = parse_field_validators(field)?;
+        let serde = if form_options.use_serde_names {
+            parse_serde_attrs(field)?
+        } else {
+            SerdeAttrs::default()
+        };
+
+        // `#[field(...)]` options only make sense on form fields, so they
+        // keep a field in the form and can't be combined with a skip
+        let field_attr = field
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("field"));
+
+        // Fields marked #[form(skip)] are left out of the form entirely
+        if is_form_skipped(field)? || serde.skip {
+            if let Some(field_attr) = field_attr {
+                return Err(syn::Error::new_spanned(
+                    field_attr,
+                    "skipped fields are not part of the form, so they cannot have #[field] options",
+                ));
+            }
+            continue;
+        }
+
+        let (mut validators, skip) = parse_field_validators(field)?;
+        let sanitizers = parse_field_sanitizers(field)?;
+        let attrs = parse_field_attrs(field)?;
+
+        // Renamed fields use the new name as their key everywhere
+        let field_name = attrs
+            .rename
+            .as_ref()
+            .or(serde.rename.as_ref())
+            .map(syn::LitStr::value)
+            .unwrap_or(field_name);
+
+        // Skipped fields are still synced, just never validated
+        if skip {
+            validators.clear();
+        }
+
+        // Literal bounds of decimal fields must fit a `Decimal`
+        let value_type = option_inner_type(&field.ty).unwrap_or(&field.ty);
+        if is_decimal_type(value_type) {
+            for (validator, _) in &validators {
+                let bounds: Vec<&RangeBound> = match validator {
+                    Validator::Range { min, max } => min.iter().chain(max).collect(),
+                    Validator::MultipleOf { step } => vec![step],
+                    _ => Vec::new(),
+                };
+                for bound in bounds {
+                    bound.to_decimal_tokens(value_type)?;
+                }
+            }
+        }
+
+        // Array and nested fields are synced from their child fields, never
+        // sanitized as a whole
+        let has_children = attrs.array || attrs.nested;
+        if has_children && !sanitizers.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "array and nested fields cannot have sanitizers; sanitize their child fields instead",
+            ));
+        }
 
-        // Only include fields that have validators
-        if !validators.is_empty() {
+        // Only include fields that have validators, sanitizers or field
+        // options, or are skipped
+        if skip || field_attr.is_some() || !validators.is_empty() || !sanitizers.is_empty() {
             field_validations.push(FieldValidation {
                 field_name,
+                field_ident: field.ident.clone().expect("named fields have idents"),
                 field_type: field.ty.clone(),
                 validators,
+                sanitizers,
+                attrs,
             });
         }
     }
 
+    // A rename must not collide with another field's key
+    for (i, fv) in field_validations.iter().enumerate() {
+        if let Some(other) = field_validations[..i]
+            .iter()
+            .find(|other| other.field_name == fv.field_name)
+        {
+            let message = format!(
+                "fields `{}` and `{}` both use the key \"{}\"",
+                other.field_ident, fv.field_ident, fv.field_name
+            );
+            return Err(
+                match fv.attrs.rename.as_ref().or(other.attrs.rename.as_ref()) {
+                    Some(rename) => syn::Error::new_spanned(rename, message),
+                    None => syn::Error::new_spanned(&fv.field_ident, message),
+                },
+            );
+        }
+    }
+
+    // A dependency must name another form field by its key
+    for fv in &field_validations {
+        for dependency in &fv.attrs.depends_on {
+            let key = dependency.value();
+            if key == fv.field_name {
+                return Err(syn::Error::new_spanned(
+                    dependency,
+                    "a field cannot depend on itself",
+                ));
+            }
+            if !field_validations
+                .iter()
+                .any(|other| other.field_name == key)
+            {
+                return Err(syn::Error::new_spanned(
+                    dependency,
+                    format!("no form field uses the key \"{}\"", key),
+                ));
+            }
+        }
+    }
+
     Ok(field_validations)
 }
 
 /// Generate validation code for a single validator
 fn generate_validator_code(
     field_name: &str,
-    _field_type: &syn::Type,
+    field_ident: &syn::Ident,
+    field_type: &syn::Type,
+    label: Option<&str>,
     validator: &Validator,
+    options: &ValidatorOptions,
 ) -> proc_macro2::TokenStream {
-    let field_ident = syn::Ident::new(field_name, proc_macro2::Span::call_site());
+    // The label rebuilds the default message, so it must come before overrides
+    let with_label = label.map(|label| quote! { .with_label(#label) });
+    let with_message = options.override_tokens();
+    let with_message = quote! { #with_label #with_message };
+
+    // Optional fields validate their inner value and skip `None`
+    let inner_type = option_inner_type(field_type);
+    let optional = inner_type.is_some();
+    let source = if optional {
+        quote! { (*field_value) }
+    } else {
+        quote! { self.#field_ident }
+    };
+    // Numeric validators compare the integer inside non-zero types
+    let number = if is_non_zero_type(inner_type.unwrap_or(field_type)) {
+        quote! { #source.get() }
+    } else {
+        source.clone()
+    };
 
     match validator {
         Validator::Required => {
@@ -390,203 +2224,1447 @@ fn generate_validator_code(
                         borang::ErrorKind::Required {
                             field: #field_name.to_string(),
                         }
-                    ));
+                    )#with_message);
                 }
             }
         }
 
-        Validator::Email => {
+        Validator::Email { mode } => {
+            let mode = mode.to_tokens();
             quote! {
-                // Email validation using regex
+                // Email validation using the shared, precompiled regex
                 let email_value = self.#field_ident.to_field_value();
-                if !email_value.is_empty() {
-                    // Simple email regex pattern
-                    let email_pattern = regex::Regex::new(
-                        r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$"
-                    ).unwrap();
-                    if !email_pattern.is_match(&email_value) {
+                if !email_value.is_empty() && !borang::validation::is_valid_email_with(&email_value, #mode) {
+                    return Err(borang::ValidationError::with_kind(
+                        borang::ErrorKind::InvalidEmail {
+                            field: #field_name.to_string(),
+                        }
+                    )#with_message);
+                }
+            }
+        }
+
+        Validator::Uuid => {
+            quote! {
+                // UUID validation - hyphenated form, using the shared, precompiled regex
+                let uuid_value = self.#field_ident.to_field_value();
+                if !uuid_value.is_empty() && !borang::validation::is_valid_uuid(&uuid_value) {
+                    return Err(borang::ValidationError::with_kind(
+                        borang::ErrorKind::InvalidUuid {
+                            field: #field_name.to_string(),
+                        }
+                    )#with_message);
+                }
+            }
+        }
+
+        Validator::HexColor => {
+            quote! {
+                // Hex color validation - `#rrggbb` or `#rgb`
+                let color_value = self.#field_ident.to_field_value();
+                if !color_value.is_empty() && !borang::validation::is_valid_hex_color(&color_value) {
+                    return Err(borang::ValidationError::with_kind(
+                        borang::ErrorKind::InvalidHexColor {
+                            field: #field_name.to_string(),
+                        }
+                    )#with_message);
+                }
+            }
+        }
+
+        Validator::Length {
+            min,
+            max,
+            equal,
+            bytes,
+        } => {
+            let min_opt = min
+                .as_ref()
+                .map(|v| quote! { Some(#v) })
+                .unwrap_or(quote! { None });
+            let max_opt = max
+                .as_ref()
+                .map(|v| quote! { Some(#v) })
+                .unwrap_or(quote! { None });
+            let equal_opt = equal
+                .as_ref()
+                .map(|v| quote! { Some(#v) })
+                .unwrap_or(quote! { None });
+
+            // Count characters by default so non-ASCII input isn't penalized
+            let length = if *bytes {
+                quote! { value.len() }
+            } else {
+                quote! { borang::validation::text_length(&value) }
+            };
+
+            let mut checks = Vec::new();
+
+            if let Some(min_val) = min {
+                checks.push(quote! {
+                    if length < #min_val {
                         return Err(borang::ValidationError::with_kind(
-                            borang::ErrorKind::InvalidEmail {
+                            borang::ErrorKind::InvalidLength {
                                 field: #field_name.to_string(),
+                                min: #min_opt,
+                                max: #max_opt,
+                                equal: #equal_opt,
                             }
-                        ));
+                        )#with_message);
+                    }
+                });
+            }
+
+            if let Some(max_val) = max {
+                checks.push(quote! {
+                    if length > #max_val {
+                        return Err(borang::ValidationError::with_kind(
+                            borang::ErrorKind::InvalidLength {
+                                field: #field_name.to_string(),
+                                min: #min_opt,
+                                max: #max_opt,
+                                equal: #equal_opt,
+                            }
+                        )#with_message);
+                    }
+                });
+            }
+
+            if let Some(equal_val) = equal {
+                checks.push(quote! {
+                    if length != #equal_val {
+                        return Err(borang::ValidationError::with_kind(
+                            borang::ErrorKind::InvalidLength {
+                                field: #field_name.to_string(),
+                                min: #min_opt,
+                                max: #max_opt,
+                                equal: #equal_opt,
+                            }
+                        )#with_message);
+                    }
+                });
+            }
+
+            skip_none(
+                field_ident,
+                optional,
+                quote! {
+                    // Length validation
+                    let value = #source.to_field_value();
+                    let length = #length;
+                    #(#checks)*
+                },
+            )
+        }
+
+        Validator::Password {
+            min_len,
+            min_classes,
+        } => skip_none(
+            field_ident,
+            optional,
+            quote! {
+                // Password strength validation
+                let value = #source.to_field_value();
+                let missing = borang::validation::password_missing(&value, #min_len, #min_classes);
+                if !missing.is_empty() {
+                    return Err(borang::ValidationError::with_kind(
+                        borang::ErrorKind::WeakPassword {
+                            field: #field_name.to_string(),
+                            missing,
+                        }
+                    )#with_message);
+                }
+            },
+        ),
+
+        Validator::Items { min, max } => {
+            let min_opt = min
+                .as_ref()
+                .map(|v| quote! { Some(#v) })
+                .unwrap_or(quote! { None });
+            let max_opt = max
+                .as_ref()
+                .map(|v| quote! { Some(#v) })
+                .unwrap_or(quote! { None });
+
+            let mut checks = Vec::new();
+
+            if let Some(min_val) = min {
+                checks.push(quote! {
+                    if count < #min_val {
+                        return Err(borang::ValidationError::with_kind(
+                            borang::ErrorKind::InvalidItemCount {
+                                field: #field_name.to_string(),
+                                min: #min_opt,
+                                max: #max_opt,
+                            }
+                        )#with_message);
                     }
+                });
+            }
+
+            if let Some(max_val) = max {
+                checks.push(quote! {
+                    if count > #max_val {
+                        return Err(borang::ValidationError::with_kind(
+                            borang::ErrorKind::InvalidItemCount {
+                                field: #field_name.to_string(),
+                                min: #min_opt,
+                                max: #max_opt,
+                            }
+                        )#with_message);
+                    }
+                });
+            }
+
+            skip_none(
+                field_ident,
+                optional,
+                quote! {
+                    // Item count validation for collections
+                    let count = #source.len();
+                    #(#checks)*
+                },
+            )
+        }
+
+        Validator::Date { after, before } => {
+            let after = after
+                .as_ref()
+                .map(|bound| {
+                    let bound = bound.to_tokens();
+                    quote! { Some(#bound) }
+                })
+                .unwrap_or(quote! { None });
+            let before = before
+                .as_ref()
+                .map(|bound| {
+                    let bound = bound.to_tokens();
+                    quote! { Some(#bound) }
+                })
+                .unwrap_or(quote! { None });
+
+            quote! {
+                // Date validation - ISO 8601 dates compare correctly as strings
+                let date_value = self.#field_ident.to_field_value();
+                if !date_value.is_empty() {
+                    let after: Option<String> = #after;
+                    let before: Option<String> = #before;
+                    let date = date_value.get(..10).unwrap_or_default();
+                    if !borang::validation::is_iso_date(date)
+                        || after.as_deref().is_some_and(|after| date <= after)
+                        || before.as_deref().is_some_and(|before| date >= before)
+                    {
+                        return Err(borang::ValidationError::with_kind(
+                            borang::ErrorKind::InvalidDate {
+                                field: #field_name.to_string(),
+                                after,
+                                before,
+                            }
+                        )#with_message);
+                    }
+                }
+            }
+        }
+
+        Validator::File { max_size, accept } => {
+            let max_size = max_size
+                .map(|max_size| quote! { Some(#max_size) })
+                .unwrap_or(quote! { None });
+            let accept = accept
+                .as_ref()
+                .map(|accept| quote! { Some(#accept) })
+                .unwrap_or(quote! { None });
+
+            quote! {
+                // File validation - check each selected file's size and type
+                if let Some(kind) = borang::validation::check_files(
+                    #field_name,
+                    &self.#field_ident.to_field_value(),
+                    #max_size,
+                    #accept,
+                ) {
+                    return Err(borang::ValidationError::with_kind(kind)#with_message);
                 }
             }
         }
 
-        Validator::Length { min, max } => {
+        Validator::Range { min, max } => {
+            // Compare in the field's native type. Integer fields with float
+            // bounds are the only case that needs a cast.
+            let value_type = inner_type.unwrap_or(field_type);
+            let decimal_field = is_decimal_type(value_type);
+            let float_field = is_float_type(value_type);
+            let float_bound = min.iter().chain(max.iter()).any(RangeBound::is_float);
+            // An integer field can't hold a fraction, so a bound like 4.5 is a
+            // mistake rather than a limit between two values
+            if is_integer_type(value_type) || is_non_zero_type(value_type) {
+                let fractional = min.iter().chain(max.iter()).find_map(|bound| match bound {
+                    RangeBound::Float(value, digits) if value.fract() != 0.0 => Some(digits.span),
+                    _ => None,
+                });
+                if let Some(span) = fractional {
+                    return syn::Error::new(
+                        span,
+                        "range bounds of an integer field must be whole numbers",
+                    )
+                    .to_compile_error();
+                }
+            }
+            let as_float = float_field || float_bound;
+            let cast = float_bound && !float_field && !decimal_field;
+            // Each bound's value to compare against and its reported bound
+            let bound_tokens = |bound: &RangeBound| {
+                if decimal_field {
+                    bound.to_decimal_tokens(value_type)
+                } else {
+                    Ok(bound.to_compare_tokens(as_float, cast))
+                }
+                .map(|compare| (compare, bound.to_error_tokens_for(value_type)))
+            };
+            let (min, max) = match (
+                min.as_ref().map(bound_tokens).transpose(),
+                max.as_ref().map(bound_tokens).transpose(),
+            ) {
+                (Ok(min), Ok(max)) => (min, max),
+                (Err(e), _) | (_, Err(e)) => return e.to_compile_error(),
+            };
             let min_opt = min
                 .as_ref()
-                .map(|v| quote! { Some(#v) })
+                .map(|(_, bound)| quote! { Some(#bound) })
                 .unwrap_or(quote! { None });
             let max_opt = max
                 .as_ref()
-                .map(|v| quote! { Some(#v) })
+                .map(|(_, bound)| quote! { Some(#bound) })
                 .unwrap_or(quote! { None });
 
             let mut checks = Vec::new();
 
-            if let Some(min_val) = min {
+            if let Some((min_val, _)) = min {
                 checks.push(quote! {
-                    if value.len() < #min_val {
+                    if value < #min_val {
                         return Err(borang::ValidationError::with_kind(
-                            borang::ErrorKind::InvalidLength {
+                            borang::ErrorKind::InvalidRange {
                                 field: #field_name.to_string(),
                                 min: #min_opt,
                                 max: #max_opt,
                             }
-                        ));
+                        )#with_message);
                     }
                 });
             }
 
-            if let Some(max_val) = max {
+            if let Some((max_val, _)) = max {
                 checks.push(quote! {
-                    if value.len() > #max_val {
+                    if value > #max_val {
                         return Err(borang::ValidationError::with_kind(
-                            borang::ErrorKind::InvalidLength {
+                            borang::ErrorKind::InvalidRange {
                                 field: #field_name.to_string(),
                                 min: #min_opt,
                                 max: #max_opt,
                             }
-                        ));
+                        )#with_message);
                     }
                 });
             }
 
+            let value = if cast {
+                quote! {
+                    // Range validation - convert to f64 for float bounds
+                    let value = #number as f64;
+                }
+            } else {
+                quote! {
+                    // Range validation - compare in the field's own type
+                    let value = #number;
+                }
+            };
+
+            skip_none(
+                field_ident,
+                optional,
+                quote! {
+                    #value
+                    #(#checks)*
+                },
+            )
+        }
+
+        Validator::Pattern { pattern } => {
             quote! {
-                // Length validation
-                let value = self.#field_ident.to_field_value();
-                #(#checks)*
+                // Pattern validation - regex is compiled once on first use
+                let pattern_value = self.#field_ident.to_field_value();
+                if !pattern_value.is_empty() {
+                    static PATTERN: std::sync::OnceLock<borang::__private::Regex> =
+                        std::sync::OnceLock::new();
+                    let pattern =
+                        PATTERN.get_or_init(|| borang::__private::Regex::new(#pattern).unwrap());
+                    if !pattern.is_match(&pattern_value) {
+                        return Err(borang::ValidationError::with_kind(
+                            borang::ErrorKind::PatternMismatch {
+                                field: #field_name.to_string(),
+                                pattern: #pattern.to_string(),
+                            }
+                        )#with_message);
+                    }
+                }
             }
         }
 
-        Validator::Range { min, max } => {
-            let min_opt = min
-                .as_ref()
-                .map(|v| quote! { Some(#v) })
-                .unwrap_or(quote! { None });
-            let max_opt = max
-                .as_ref()
-                .map(|v| quote! { Some(#v) })
-                .unwrap_or(quote! { None });
+        Validator::MultipleOf { step } => {
+            let value_type = inner_type.unwrap_or(field_type);
+            let float_field = is_float_type(value_type);
+            let step_error = step.to_error_tokens_for(value_type);
+            let as_float = float_field || step.is_float();
+            let step_val = step.to_compare_tokens(as_float, false);
 
-            let mut checks = Vec::new();
+            let check = if is_decimal_type(value_type) {
+                let step_val = match step.to_decimal_tokens(value_type) {
+                    Ok(step_val) => step_val,
+                    Err(e) => return e.to_compile_error(),
+                };
+                quote! {
+                    // Multiple-of validation - exact for decimals, so 0.3 is a
+                    // multiple of 0.1 without a tolerance
+                    let value = #number;
+                    let invalid = !(value % #step_val).is_zero();
+                }
+            } else if as_float {
+                // Integer fields with a float step are checked as f64
+                let (value, float_ty) = if float_field {
+                    let ty = inner_type.unwrap_or(field_type);
+                    (quote! { #source }, quote! { #ty })
+                } else {
+                    (quote! { #number as f64 }, quote! { f64 })
+                };
+                quote! {
+                    // Multiple-of validation - allow for float rounding error,
+                    // so 0.3 counts as a multiple of 0.1
+                    let value = #value;
+                    let quotient = value / #step_val;
+                    let tolerance = 8.0 * <#float_ty>::EPSILON * quotient.abs().max(1.0);
+                    let invalid = (quotient - quotient.round()).abs() > tolerance;
+                }
+            } else {
+                quote! {
+                    // Multiple-of validation - exact for integers
+                    let value = #number;
+                    // `is_multiple_of` only exists for unsigned integers
+                    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+                    let invalid = value % #step_val != 0;
+                }
+            };
 
-            if let Some(min_val) = min {
-                checks.push(quote! {
-                    if value < #min_val {
+            skip_none(
+                field_ident,
+                optional,
+                quote! {
+                    #check
+                    if invalid {
                         return Err(borang::ValidationError::with_kind(
-                            borang::ErrorKind::InvalidRange {
+                            borang::ErrorKind::NotMultipleOf {
                                 field: #field_name.to_string(),
-                                min: #min_opt,
-                                max: #max_opt,
+                                step: #step_error,
                             }
-                        ));
+                        )#with_message);
                     }
-                });
-            }
+                },
+            )
+        }
 
-            if let Some(max_val) = max {
-                checks.push(quote! {
-                    if value > #max_val {
+        Validator::Sign { sign } => {
+            let value_type = inner_type.unwrap_or(field_type);
+            let zero = if is_float_type(value_type) {
+                quote! { 0.0 }
+            } else if is_decimal_type(value_type) {
+                quote! { <#value_type>::ZERO }
+            } else {
+                quote! { 0 }
+            };
+            let (invalid, constraint) = match sign {
+                SignConstraint::Positive => (
+                    quote! { value <= #zero },
+                    quote! { borang::SignConstraint::Positive },
+                ),
+                SignConstraint::NonNegative => (
+                    quote! { value < #zero },
+                    quote! { borang::SignConstraint::NonNegative },
+                ),
+                SignConstraint::NonZero => (
+                    quote! { value == #zero },
+                    quote! { borang::SignConstraint::NonZero },
+                ),
+            };
+
+            skip_none(
+                field_ident,
+                optional,
+                quote! {
+                    // Sign validation - compare in the field's own type
+                    let value = #number;
+                    // Unsigned fields make some of these checks trivially true
+                    #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
+                    let invalid = #invalid;
+                    if invalid {
                         return Err(borang::ValidationError::with_kind(
-                            borang::ErrorKind::InvalidRange {
+                            borang::ErrorKind::InvalidSign {
                                 field: #field_name.to_string(),
-                                min: #min_opt,
-                                max: #max_opt,
+                                sign: #constraint,
                             }
-                        ));
+                        )#with_message);
                     }
-                });
+                },
+            )
+        }
+
+        Validator::OneOf { allowed } => {
+            quote! {
+                // Allowed values validation
+                let one_of_value = self.#field_ident.to_field_value();
+                const ALLOWED: &[&str] = &[#(#allowed),*];
+                if !one_of_value.is_empty() && !ALLOWED.contains(&one_of_value.as_str()) {
+                    return Err(borang::ValidationError::with_kind(
+                        borang::ErrorKind::NotOneOf {
+                            field: #field_name.to_string(),
+                            allowed: ALLOWED.iter().map(|value| value.to_string()).collect(),
+                        }
+                    )#with_message);
+                }
+            }
+        }
+
+        // Spanned at the attribute, so a missing method or wrong signature is
+        // reported there rather than on the derive
+        Validator::Custom { method } => quote_spanned! {method.span()=>
+            // Custom validation
+            let validate: fn(&Self) -> borang::ValidationResult = Self::#method;
+            validate(self)?;
+        },
+
+        Validator::CustomValue { function, span } => {
+            let mut field_ident = field_ident.clone();
+            field_ident.set_span(*span);
+            quote_spanned! {*span=>
+                // Custom validation with the field value
+                #function(#field_name, &self.#field_ident)?;
+            }
+        }
+
+        Validator::Any { validators } => {
+            let checks = validators.iter().map(|(validator, options)| {
+                let code = generate_validator_code(
+                    field_name,
+                    field_ident,
+                    field_type,
+                    label,
+                    validator,
+                    options,
+                );
+                options.wrap_cfg(code)
+            });
+            let map_err =
+                (!with_message.is_empty()).then(|| quote! { .map_err(|e| e #with_message) });
+            quote! {
+                // Any validation - passes as soon as one validator passes,
+                // otherwise fails with the first validator's error
+                let check_any = || -> borang::ValidationResult {
+                    let mut first_error = None;
+                    #(
+                        let check = || -> borang::ValidationResult {
+                            #checks
+                            Ok(())
+                        };
+                        match check() {
+                            Ok(()) => return Ok(()),
+                            Err(e) => {
+                                first_error.get_or_insert(e);
+                            }
+                        }
+                    )*
+                    first_error.map_or(Ok(()), Err)
+                };
+                check_any()#map_err?;
+            }
+        }
+
+        Validator::Registered { name } => {
+            let map_err =
+                (!with_message.is_empty()).then(|| quote! { .map_err(|e| e #with_message) });
+            skip_none(
+                field_ident,
+                optional,
+                quote! {
+                    // Registered rule validation
+                    borang::rules::validate_registered(#name, #field_name, &#source.to_field_value())
+                        #map_err?;
+                },
+            )
+        }
+
+        Validator::Rule { rule, span } => {
+            let mut field_ident = field_ident.clone();
+            field_ident.set_span(*span);
+            let map_err =
+                (!with_message.is_empty()).then(|| quote! { .map_err(|e| e #with_message) });
+            quote_spanned! {*span=>
+                // Programmatic rule validation
+                borang::rules::ValidationRule::validate(&(#rule), #field_name, &self.#field_ident)
+                    #map_err?;
+            }
+        }
+    }
+}
+
+/// Give every token in generated code the same span
+///
+/// Spans can't be joined on stable, so the validator's name stands in for the
+/// whole attribute item.
+fn respan(tokens: proc_macro2::TokenStream, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let proc_macro2::TokenTree::Group(group) = &token {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = proc_macro2::TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
             }
+            token
+        })
+        .collect()
+}
+
+/// Generate the validate_field match arm for a single field
+fn generate_validate_field_arm(field_validation: &FieldValidation) -> proc_macro2::TokenStream {
+    let field_name = &field_validation.field_name;
+    let validators = generate_field_validators(field_validation);
 
+    // Warnings don't stop validation, so a later error still takes precedence
+    let has_warnings = validators.iter().any(|(_, warning)| *warning);
+    let validator_code = validators.iter().map(|(code, warning)| {
+        if *warning {
             quote! {
-                // Range validation - convert to i64 for comparison
-                let value = self.#field_ident as i64;
-                #(#checks)*
+                let check = || -> borang::ValidationResult {
+                    #code
+                    Ok(())
+                };
+                if let Err(e) = check() {
+                    warning.get_or_insert(e.with_severity(borang::Severity::Warning));
+                }
+            }
+        } else {
+            code.clone()
+        }
+    });
+
+    if has_warnings {
+        quote! {
+            #field_name => {
+                let mut warning = None;
+                #(#validator_code)*
+                match warning {
+                    Some(warning) => Err(warning),
+                    None => Ok(()),
+                }
+            }
+        }
+    } else {
+        quote! {
+            #field_name => {
+                #(#validator_code)*
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Generate the validate_field_all match arm for a single field
+///
+/// Each validator runs in its own closure, so an early `return Err(..)` only
+/// ends that validator and every failure is collected.
+fn generate_validate_field_all_arm(field_validation: &FieldValidation) -> proc_macro2::TokenStream {
+    let field_name = &field_validation.field_name;
+    let validator_code =
+        generate_field_validators(field_validation)
+            .into_iter()
+            .map(|(code, warning)| {
+                let severity =
+                    warning.then(|| quote! { .with_severity(borang::Severity::Warning) });
+                quote! {
+                    let check = || -> borang::ValidationResult {
+                        #code
+                        Ok(())
+                    };
+                    if let Err(e) = check() {
+                        errors.push(e #severity);
+                    }
+                }
+            });
+
+    quote! {
+        #field_name => {
+            let mut errors = Vec::new();
+            #(#validator_code)*
+            errors
+        }
+    }
+}
+
+/// Generate the validation code of each validator on a field, in order,
+/// paired with whether its failures are warnings
+fn generate_field_validators(
+    field_validation: &FieldValidation,
+) -> Vec<(proc_macro2::TokenStream, bool)> {
+    let field_name = &field_validation.field_name;
+    let field_ident = &field_validation.field_ident;
+    let field_type = &field_validation.field_type;
+    let label = field_validation.attrs.label.as_deref();
+
+    field_validation
+        .validators
+        .iter()
+        .map(|(v, options)| {
+            let code =
+                generate_validator_code(field_name, field_ident, field_type, label, v, options);
+            // Point type errors in the generated code at the validator's attribute.
+            // Custom validators and rules already carry the span of their literal.
+            let code = match (v, options.span) {
+                (
+                    Validator::Custom { .. }
+                    | Validator::CustomValue { .. }
+                    | Validator::Rule { .. },
+                    _,
+                )
+                | (_, None) => code,
+                (_, Some(span)) => respan(code, span),
+            };
+            (options.wrap_cfg(code), options.warning)
+        })
+        .collect()
+}
+
+/// Derive macro for generating form validation implementations.
+///
+/// This macro generates the `FormValidation` trait implementation for a struct,
+/// parsing `#[validator(...)]` attributes on fields to generate validation logic.
+///
+/// Validators that only need the field's value can use
+/// `#[validator(custom_value = "check_slug")]`, which calls
+/// `Self::check_slug(field_name, &self.field)`. A path such as
+/// `"validators::check_slug"` calls a free function instead, so the same
+/// validator can be shared between forms. The function must have the signature
+/// `fn(&str, &T) -> ValidationResult`.
+///
+/// `#[validator(email(mode = "html5"))]` picks how strictly addresses are
+/// checked: `"simple"` (the default), `"html5"` to match the browser's
+/// `type="email"` check, or `"rfc5322"` for the full addr-spec.
+///
+/// `#[validator(hex_color)]` checks that a `String` field holds a color like
+/// `#ff8800` or `#f80`. `HexColor` fields are checked when they are parsed.
+///
+/// `#[validator(file(max_size_mb = 5, accept = "image/*"))]` checks the files
+/// of a `FileInfo` field, as filled in by the `FileInput` component. `accept`
+/// takes the same list as the HTML attribute, e.g. `"image/*,.pdf"`.
+///
+/// `#[validator(any(email, pattern = "^[0-9]+$"))]` passes when at least one of
+/// the listed validators passes, and otherwise reports the first one's error.
+///
+/// `#[validator(rule = "username_rules()")]` runs a programmatic
+/// `borang::rules::ValidationRule` against `&self.field`. The string is any
+/// expression evaluating to a rule, so rules built in code and attribute
+/// validators share one form. `#[validator(registered = "strong_password")]`
+/// runs a rule registered at startup with `borang::rules::register_rule`;
+/// a name with no registered rule fails with the `unregistered_rule` code.
+///
+/// Fields can be cleaned up before parsing and validation with
+/// `#[sanitizer(trim, lowercase)]`. Available sanitizers are `trim`, `trim_start`,
+/// `trim_end`, `lowercase` and `uppercase`, applied in the order given. The
+/// input value shown to the user is left untouched.
+///
+/// `#[validator(skip)]` turns off all validation for a field while keeping it in
+/// the form. A single validator can be compiled in conditionally with a `cfg`
+/// option, e.g. `length(min = 12, cfg = "not(debug_assertions)")`. Bare forms
+/// take it as a following item: `custom = "check_domain", cfg = "not(test)"`.
+///
+/// A validator with `severity = "warning"`, e.g.
+/// `#[validator(length(max = 200), severity = "warning")]`, reports soft
+/// constraints: its error is surfaced like any other but doesn't make
+/// `Form::validate` return false, and a later hard error takes precedence.
+///
+/// `#[field(label = "Email address")]` gives a field a human-friendly name for
+/// default error messages and the `Label` component. `#[field(rename = "emailAddress")]`
+/// changes the key the field is known by in the form, errors and `to_strings`,
+/// so it can follow external naming conventions.
+///
+/// `#[field(mask = "####-####-####-####")]` shows the field's value formatted
+/// in `Input` and `Textarea` while the form stores and validates it without
+/// the mask's characters. `#` takes a digit, `A` a letter and `*` either.
+///
+/// `#[field(array)]` marks a `Vec` field whose items derive `FormValidation`
+/// themselves, like `phones: Vec<Phone>`. Item fields are addressed as
+/// `phones[0].number`, and are managed with `Form::field_array` and rendered
+/// with the `FieldArray` component.
+///
+/// `#[field(nested)]` marks a field whose type derives `FormValidation`, like
+/// `address: Address`, so reusable sections can be shared across forms. Its
+/// fields are addressed with dotted paths such as `address.street`, and are
+/// validated, synced and dirty-tracked like the parent's own fields.
+///
+/// `#[field(depends_on = "password")]` revalidates a field whenever the named
+/// field is validated, so e.g. a confirmation field's "must match" error is
+/// recomputed as the password changes. Only a field that is touched, dirty or
+/// already has an error is revalidated, so the user isn't told to confirm a
+/// password they are still typing. It may be repeated to depend on several
+/// fields.
+///
+/// A field is part of the form when it has validators, sanitizers or
+/// `#[field(...)]` options, or is marked `#[validator(skip)]` to keep it in
+/// the form without validating it. Other fields are left out.
+/// `#[form(skip)]` leaves a field out entirely, even if it has validators, so
+/// internal fields like ids or timestamps never become form fields. A skipped
+/// field with `#[field(...)]` options is a compile error.
+///
+/// Each form field also gets an associated constant holding its key, named
+/// after the field in upper case, e.g. `ContactForm::EMAIL`. Using these in
+/// `<Field name=ContactForm::EMAIL>` turns typos into compile errors.
+///
+/// The derive also emits a `{Name}Errors` struct with an
+/// `Option<ValidationError>` per form field, returned by `validate_typed()`, so
+/// errors can be matched on without string lookups. It converts from the
+/// `HashMap` returned by `validate_all`: form-level errors stored under
+/// `FORM_ERROR_KEY` go to its `form` slot, and errors of any other key, such
+/// as field array items, to its `other` map. Form fields can't be named `form`
+/// or `other`.
+///
+/// `field_meta()` describes every form field: its key, type as written, label,
+/// whether it is required, and its validators with their parameters. It backs
+/// generated UIs and schema exports.
+///
+/// `validate_field_all` runs every validator of a field instead of stopping at
+/// the first failure, which backs `ErrorMode::All` on `Form`.
+///
+/// A `{Name}FormExt` trait is generated too, implemented for `Form<{Name}>`, with
+/// a `Signal<String>` getter and a validating setter per form field, e.g.
+/// `form.email()` and `form.set_email("jed@inspire.my")`. Bring it into scope to
+/// use it. Inherent `Form` methods win over getters of the same name, such as a
+/// field called `data`.
+///
+/// With `#[form(use_serde_names)]` on the struct, `#[serde(rename = "...")]`
+/// sets a field's key and `#[serde(skip)]` leaves it out of the form, so form
+/// field names match the JSON payload. `#[field(rename)]` still takes precedence.
+///
+/// Numeric parameters of `length`, `items`, `range`, `multiple_of` and `password`
+/// accept constants and expressions as well as literals, e.g.
+/// `length(min = PASSWORD_MIN)`. They are emitted as written, so they must have
+/// the type the comparison needs: `usize` for lengths and counts, and the
+/// field's own type for `range` and `multiple_of`.
+///
+/// On `rust_decimal::Decimal` fields, literal `range` and `multiple_of` bounds
+/// are turned into `Decimal` values instead of `f64`, so
+/// `range(min = 0.01, max = 9999.99)` and `multiple_of = 0.01` compare the
+/// amount exactly and report the exact bound. A literal that doesn't fit a
+/// `Decimal` is a compile error, as is a `range` bound with a fraction, e.g.
+/// `range(max = 4.5)`, on an integer field.
+///
+/// Cross-field rules can be added with `#[form_validator("method_name")]` on the
+/// struct. The method must have the signature `fn(&self) -> ValidationResult` and
+/// runs in `validate_all`. Its error is stored under the error's `field`, so use
+/// a field name to attribute it to that field or `ValidationError::form` for a
+/// form-level error.
+///
+/// Generic structs are supported. Bound type parameters used as fields by
+/// `FromFieldValue`, e.g. `struct AmountForm<T: FromFieldValue + PartialOrd>`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(FormValidation, Default, Clone)]
+/// #[form_validator("check_passwords_match")]
+/// pub struct SignUpForm {
+///     #[validator(required)]
+///     name: String,
+///
+///     #[sanitizer(trim, lowercase)]
+///     #[validator(required, email)]
+///     email: String,
+///
+///     #[validator(required, length(min = 8))]
+///     password: String,
+///
+///     #[validator(required)]
+///     confirm_password: String,
+/// }
+///
+/// impl SignUpForm {
+///     fn check_passwords_match(&self) -> borang::ValidationResult {
+///         if self.password != self.confirm_password {
+///             return Err(borang::ValidationError::new(
+///                 "confirm_password",
+///                 "Passwords do not match",
+///             ));
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+#[proc_macro_derive(
+    FormValidation,
+    attributes(validator, form_validator, sanitizer, form, field)
+)]
+pub fn derive_form_validation(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let form_options = match parse_form_options(&input.attrs) {
+        Ok(options) => options,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+
+    // Extract field validations
+    let field_validations = match extract_field_validations(&input.data, &form_options) {
+        Ok(validations) => validations,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+
+    // Extract struct-level validators
+    let form_validators = match extract_form_validators(&input.attrs) {
+        Ok(methods) => methods,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+
+    // Generate validate_field match arms
+    let validate_field_arms: Vec<_> = field_validations
+        .iter()
+        .map(generate_validate_field_arm)
+        .collect();
+
+    // Generate validate_field_all match arms
+    let validate_field_all_arms: Vec<_> = field_validations
+        .iter()
+        .map(generate_validate_field_all_arm)
+        .collect();
+
+    // Generate match arms delegating indexed names like `phones[0].number` to
+    // the items of array fields, and dotted paths like `address.street` to
+    // nested fields
+    let validate_path_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_ident = &fv.field_ident;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::validate_item(&self.#field_ident, #field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::validate_nested_field(&self.#field_ident, #field_name, name)
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    let validate_path_all_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_ident = &fv.field_ident;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::validate_item_all(&self.#field_ident, #field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::validate_nested_field_all(&self.#field_ident, #field_name, name)
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    let path_label_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_type = &fv.field_type;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::item_label::<<#field_type as IntoIterator>::Item>(#field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::nested_label::<#field_type>(#field_name, name)
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    let path_required_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_type = &fv.field_type;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::item_required::<<#field_type as IntoIterator>::Item>(#field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::nested_required::<#field_type>(#field_name, name)
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    let path_format_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_type = &fv.field_type;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::item_format::<<#field_type as IntoIterator>::Item>(#field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::nested_format::<#field_type>(#field_name, name)
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    let path_text_bounds_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_type = &fv.field_type;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::item_text_bounds::<<#field_type as IntoIterator>::Item>(#field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::nested_text_bounds::<#field_type>(#field_name, name)
+                    }
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    let path_number_bounds_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let field_type = &fv.field_type;
+            if fv.attrs.array {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, "[")) => {
+                        borang::field_array::item_number_bounds::<<#field_type as IntoIterator>::Item>(#field_name, name)
+                    }
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    name if name.starts_with(concat!(#field_name, ".")) => {
+                        borang::nested::nested_number_bounds::<#field_type>(#field_name, name)
+                    }
+                })
+            } else {
+                None
             }
-        }
+        })
+        .collect();
+
+    // Generate field names list
+    let field_names: Vec<_> = field_validations.iter().map(|fv| &fv.field_name).collect();
 
-        Validator::Custom { method_name } => {
-            let method_ident = syn::Ident::new(method_name, proc_macro2::Span::call_site());
+    // Generate field key constants, e.g. `ContactForm::EMAIL`
+    let field_constants: Vec<_> = field_validations
+        .iter()
+        .map(|fv| {
+            let field_name = &fv.field_name;
+            let const_ident = syn::Ident::new(
+                &fv.field_ident.unraw().to_string().to_uppercase(),
+                fv.field_ident.span(),
+            );
+            let doc = format!("Form key of the `{}` field", fv.field_ident.unraw());
             quote! {
-                // Custom validation
-                self.#method_ident()?;
+                #[doc = #doc]
+                pub const #const_ident: &'static str = #field_name;
             }
-        }
+        })
+        .collect();
+
+    // Generate the typed errors struct, e.g. `ContactFormErrors`
+    let errors_name = syn::Ident::new(&format!("{}Errors", name), name.span());
+    if let Some(fv) = field_validations
+        .iter()
+        .find(|fv| fv.field_ident == "form" || fv.field_ident == "other")
+    {
+        let message = format!(
+            "`{}` is reserved for the errors without a field of their own in `{}`",
+            fv.field_ident, errors_name
+        );
+        return TokenStream::from(
+            syn::Error::new(fv.field_ident.span(), message).to_compile_error(),
+        );
     }
-}
+    let errors_doc = format!(
+        "Validation errors of [`{}`], one optional error per form field",
+        name
+    );
+    let vis = &input.vis;
+    let errors_fields: Vec<_> = field_validations
+        .iter()
+        .map(|fv| {
+            let field_ident = &fv.field_ident;
+            quote! { pub #field_ident: Option<borang::ValidationError>, }
+        })
+        .collect();
+    let errors_from_map: Vec<_> = field_validations
+        .iter()
+        .map(|fv| {
+            let field_name = &fv.field_name;
+            let field_ident = &fv.field_ident;
+            quote! { #field_ident: errors.remove(#field_name), }
+        })
+        .collect();
+    let errors_any: Vec<_> = field_validations
+        .iter()
+        .map(|fv| {
+            let field_ident = &fv.field_ident;
+            quote! { || self.#field_ident.as_ref().is_some_and(|e| !e.is_warning()) }
+        })
+        .collect();
 
-/// Generate the validate_field match arm for a single field
-fn generate_validate_field_arm(field_validation: &FieldValidation) -> proc_macro2::TokenStream {
-    let field_name = &field_validation.field_name;
-    let field_type = &field_validation.field_type;
+    // Generate typed accessors on `Form<T>`, e.g. `form.email()` and `form.set_email(..)`
+    let ext_name = syn::Ident::new(&format!("{}FormExt", name), name.span());
+    let ext_doc = format!(
+        "Typed access to the fields of a `Form<{}>`, generated by `FormValidation`",
+        name
+    );
+    let (ext_signatures, ext_methods): (Vec<_>, Vec<_>) = field_validations
+        .iter()
+        .map(|fv| {
+            let field_name = &fv.field_name;
+            let getter = &fv.field_ident;
+            let setter = syn::Ident::new(
+                &format!("set_{}", fv.field_ident.unraw()),
+                fv.field_ident.span(),
+            );
+            // Array fields have no value of their own, only items
+            if fv.attrs.array {
+                let doc = format!("Items of the `{}` array field", field_name);
+                let signatures = quote! {
+                    #[doc = #doc]
+                    fn #getter(&self) -> borang::FieldArrayState<#name #ty_generics>;
+                };
+                let methods = quote! {
+                    fn #getter(&self) -> borang::FieldArrayState<#name #ty_generics> {
+                        self.field_array(#field_name)
+                    }
+                };
+                return (signatures, methods);
+            }
+            // Nested fields have no value of their own either, only child fields
+            if fv.attrs.nested {
+                return (quote! {}, quote! {});
+            }
+            let getter_doc = format!("Value signal of the `{}` field", field_name);
+            let setter_doc = format!("Set the `{}` field and validate it", field_name);
+            let signatures = quote! {
+                #[doc = #getter_doc]
+                fn #getter(&self) -> leptos::prelude::Signal<String>;
+                #[doc = #setter_doc]
+                fn #setter(&self, value: impl Into<String>);
+            };
+            let methods = quote! {
+                fn #getter(&self) -> leptos::prelude::Signal<String> {
+                    self.field_value(#field_name).into()
+                }
+                fn #setter(&self, value: impl Into<String>) {
+                    self.set_field_value(#field_name, value)
+                }
+            };
+            (signatures, methods)
+        })
+        .unzip();
+    let ext_where = where_clause.map(|clause| &clause.predicates);
 
-    let validator_code: Vec<_> = field_validation
-        .validators
+    // Generate field_label match arms
+    let field_label_arms: Vec<_> = field_validations
         .iter()
-        .map(|v| generate_validator_code(field_name, field_type, v))
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let label = fv.attrs.label.as_ref()?;
+            Some(quote! { #field_name => Some(#label), })
+        })
         .collect();
 
-    quote! {
-        #field_name => {
-            #(#validator_code)*
-            Ok(())
-        }
-    }
-}
+    // Generate field_format match arms for fields with a mask
+    let field_format_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let mask = fv.attrs.mask.as_ref()?;
+            Some(quote! { #field_name => Some(borang::FieldFormat::Mask(#mask)), })
+        })
+        .collect();
 
-/// Derive macro for generating form validation implementations.
-///
-/// This macro generates the `FormValidation` trait implementation for a struct,
-/// parsing `#[validator(...)]` attributes on fields to generate validation logic.
-///
-/// # Example
-///
-/// ```ignore
-/// #[derive(FormValidation, Default, Clone)]
-/// pub struct SignUpForm {
-///     #[validator(required)]
-///     name: String,
-///
-///     #[validator(required, email)]
-///     email: String,
-///
-///     #[validator(required, length(min = 8))]
-///     password: String,
-/// }
-/// ```
-#[proc_macro_derive(FormValidation, attributes(validator))]
-pub fn derive_form_validation(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let name = &input.ident;
+    // Generate is_field_required match arms for fields with a blocking
+    // `required` validator
+    let field_required_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let (_, options) = fv.validators.iter().find(|(validator, options)| {
+                matches!(validator, Validator::Required) && !options.warning
+            })?;
+            Some(match &options.cfg {
+                Some(cfg) => quote! { #field_name => cfg!(#cfg), },
+                None => quote! { #field_name => true, },
+            })
+        })
+        .collect();
 
-    // Extract field validations
-    let field_validations = match extract_field_validations(&input.data) {
-        Ok(validations) => validations,
-        Err(e) => return TokenStream::from(e.to_compile_error()),
-    };
+    // Generate number_bounds match arms from blocking `range` and
+    // `multiple_of` validators
+    let number_bounds_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let (mut min, mut max, mut step) = (None, None, None);
+            for (validator, options) in &fv.validators {
+                if options.warning {
+                    continue;
+                }
+                let bound_tokens = |bound: &RangeBound| {
+                    let value_type = option_inner_type(&fv.field_type).unwrap_or(&fv.field_type);
+                    let bound = bound.to_error_tokens_for(value_type);
+                    match &options.cfg {
+                        Some(cfg) => quote! { if cfg!(#cfg) { Some(#bound) } else { None } },
+                        None => quote! { Some(#bound) },
+                    }
+                };
+                match validator {
+                    Validator::Range {
+                        min: range_min,
+                        max: range_max,
+                    } => {
+                        min = range_min.as_ref().map(bound_tokens).or(min);
+                        max = range_max.as_ref().map(bound_tokens).or(max);
+                    }
+                    Validator::MultipleOf { step: multiple } => {
+                        step = Some(bound_tokens(multiple));
+                    }
+                    _ => {}
+                }
+            }
+            if min.is_none() && max.is_none() && step.is_none() {
+                return None;
+            }
+            let [min, max, step] = [min, max, step].map(|bound| bound.unwrap_or(quote! { None }));
+            Some(quote! {
+                #field_name => borang::NumberBounds { min: #min, max: #max, step: #step },
+            })
+        })
+        .collect();
 
-    // Generate validate_field match arms
-    let validate_field_arms: Vec<_> = field_validations
+    // Generate text_bounds match arms from blocking `length`, `pattern` and
+    // `password` validators
+    let text_bounds_arms: Vec<_> = field_validations
         .iter()
-        .map(generate_validate_field_arm)
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let (mut min_length, mut max_length, mut pattern, mut password) =
+                (None, None, None, None);
+            for (validator, options) in &fv.validators {
+                if options.warning {
+                    continue;
+                }
+                let bound_tokens = |bound: &proc_macro2::TokenStream| match &options.cfg {
+                    Some(cfg) => quote! { if cfg!(#cfg) { Some(#bound) } else { None } },
+                    None => quote! { Some(#bound) },
+                };
+                match validator {
+                    Validator::Length {
+                        min,
+                        max,
+                        equal,
+                        bytes: false,
+                    } => {
+                        let min = equal.as_ref().or(min.as_ref());
+                        let max = equal.as_ref().or(max.as_ref());
+                        min_length = min.map(bound_tokens).or(min_length);
+                        max_length = max.map(bound_tokens).or(max_length);
+                    }
+                    Validator::Pattern { pattern: regex } => {
+                        if let Some(regex) = html_pattern(regex) {
+                            pattern = Some(bound_tokens(&quote! { #regex }));
+                        }
+                    }
+                    Validator::Password {
+                        min_len,
+                        min_classes,
+                    } => {
+                        password = Some(bound_tokens(&quote! { (#min_len, #min_classes) }));
+                    }
+                    _ => {}
+                }
+            }
+            if min_length.is_none()
+                && max_length.is_none()
+                && pattern.is_none()
+                && password.is_none()
+            {
+                return None;
+            }
+            let [min_length, max_length, pattern, password] =
+                [min_length, max_length, pattern, password]
+                    .map(|bound| bound.unwrap_or(quote! { None }));
+            Some(quote! {
+                #field_name => borang::TextBounds {
+                    min_length: #min_length,
+                    max_length: #max_length,
+                    pattern: #pattern,
+                    password: #password,
+                },
+            })
+        })
         .collect();
 
-    // Generate field names list
-    let field_names: Vec<_> = field_validations.iter().map(|fv| &fv.field_name).collect();
+    // Generate field_meta entries, with each validator compiled in only when
+    // its `cfg` holds
+    let field_meta_items: Vec<_> = field_validations
+        .iter()
+        .map(|fv| {
+            let field_name = &fv.field_name;
+            let type_name = type_name(&fv.field_type);
+            let compound = fv.attrs.array || fv.attrs.nested;
+            let validators: Vec<_> = fv
+                .validators
+                .iter()
+                .map(|(validator, options)| {
+                    let (name, params) = validator_meta(validator);
+                    let warning = options.warning;
+                    let meta = quote! {
+                        borang::ValidatorMeta {
+                            name: #name,
+                            params: vec![#(#params),*],
+                            warning: #warning,
+                        }
+                    };
+                    match &options.cfg {
+                        Some(cfg) => quote! { cfg!(#cfg).then(|| #meta) },
+                        None => quote! { Some(#meta) },
+                    }
+                })
+                .collect();
+            quote! {
+                borang::FieldMeta {
+                    name: #field_name,
+                    type_name: #type_name,
+                    label: Self::field_label(#field_name),
+                    required: Self::is_field_required(#field_name),
+                    validators: {
+                        let validators: Vec<Option<borang::ValidatorMeta>> = vec![#(#validators),*];
+                        validators.into_iter().flatten().collect()
+                    },
+                    compound: #compound,
+                }
+            }
+        })
+        .collect();
+
+    // Generate field_dependents match arms, the reverse of `depends_on`
+    let field_dependents_arms: Vec<_> = field_validations
+        .iter()
+        .filter_map(|fv| {
+            let field_name = &fv.field_name;
+            let dependents: Vec<_> = field_validations
+                .iter()
+                .filter(|other| {
+                    other
+                        .attrs
+                        .depends_on
+                        .iter()
+                        .any(|dependency| dependency.value() == *field_name)
+                })
+                .map(|other| &other.field_name)
+                .collect();
+            if dependents.is_empty() {
+                return None;
+            }
+            Some(quote! { #field_name => vec![#(#dependents),*], })
+        })
+        .collect();
 
     // Generate validate_all implementation
     let validate_all_calls: Vec<_> = field_validations
         .iter()
         .map(|fv| {
             let field_name = &fv.field_name;
+            let field_ident = &fv.field_ident;
+            let validate_children = if fv.attrs.array {
+                Some(quote! {
+                    borang::field_array::validate_items(&self.#field_ident, #field_name, &mut errors);
+                })
+            } else if fv.attrs.nested {
+                Some(quote! {
+                    borang::nested::validate_nested(&self.#field_ident, #field_name, &mut errors);
+                })
+            } else {
+                None
+            };
             quote! {
                 if let Err(e) = self.validate_field(#field_name) {
                     errors.insert(#field_name.to_string(), e);
                 }
+                #validate_children
+            }
+        })
+        .collect();
+
+    // Generate struct-level validator calls - field errors take precedence
+    let form_validator_calls: Vec<_> = form_validators
+        .iter()
+        .map(|method| {
+            quote_spanned! {method.span()=>
+                let validate: fn(&Self) -> borang::ValidationResult = Self::#method;
+                if let Err(e) = validate(self) {
+                    errors.entry(e.field.clone()).or_insert(e);
+                }
             }
         })
         .collect();
@@ -596,15 +3674,29 @@ pub fn derive_form_validation(input: TokenStream) -> TokenStream {
         .iter()
         .map(|fv| {
             let field_name = &fv.field_name;
-            let field_ident = syn::Ident::new(field_name, proc_macro2::Span::call_site());
+            let field_ident = &fv.field_ident;
             let field_type = &fv.field_type;
+            let sanitizers = fv.sanitizers.iter().map(|sanitizer| sanitizer.to_tokens());
+            let with_label = fv.attrs.label.as_ref().map(|label| quote! { .with_label(#label) });
+
+            if fv.attrs.array {
+                return quote! {
+                    errors.extend(borang::field_array::sync_items(&mut self.#field_ident, #field_name, fields));
+                };
+            }
+            if fv.attrs.nested {
+                return quote! {
+                    errors.extend(borang::nested::sync_nested(&mut self.#field_ident, #field_name, fields));
+                };
+            }
 
             quote! {
                 if let Some(field) = fields.get(#field_name) {
                     let value = field.value.get_untracked();
+                    #(#sanitizers)*
                     match <#field_type as borang::FromFieldValue>::from_field_value(#field_name, &value) {
                         Ok(parsed) => self.#field_ident = parsed,
-                        Err(e) => { errors.insert(#field_name.to_string(), e); }
+                        Err(e) => { errors.insert(#field_name.to_string(), e #with_label); }
                     }
                 }
             }
@@ -616,7 +3708,18 @@ pub fn derive_form_validation(input: TokenStream) -> TokenStream {
         .iter()
         .map(|fv| {
             let field_name = &fv.field_name;
-            let field_ident = syn::Ident::new(field_name, proc_macro2::Span::call_site());
+            let field_ident = &fv.field_ident;
+
+            if fv.attrs.array {
+                return quote! {
+                    borang::field_array::items_to_strings(&self.#field_ident, #field_name, &mut map);
+                };
+            }
+            if fv.attrs.nested {
+                return quote! {
+                    borang::nested::nested_to_strings(&self.#field_ident, #field_name, &mut map);
+                };
+            }
 
             quote! {
                 map.insert(#field_name.to_string(), borang::FromFieldValue::to_field_value(&self.#field_ident));
@@ -625,7 +3728,58 @@ pub fn derive_form_validation(input: TokenStream) -> TokenStream {
         .collect();
 
     let expanded = quote! {
-        impl borang::FormValidation for #name {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#field_constants)*
+
+            /// Validate all fields, returning the errors as a typed struct
+            pub fn validate_typed(&self) -> #errors_name {
+                #errors_name::from(borang::FormValidation::validate_all(self))
+            }
+        }
+
+        #[doc = #ext_doc]
+        #vis trait #ext_name {
+            #(#ext_signatures)*
+        }
+
+        impl #impl_generics #ext_name for borang::Form<#name #ty_generics>
+        where
+            #name #ty_generics: Default + Clone + Send + Sync + 'static,
+            #ext_where
+        {
+            #(#ext_methods)*
+        }
+
+        #[doc = #errors_doc]
+        #[derive(Clone, Debug, Default)]
+        #vis struct #errors_name {
+            #(#errors_fields)*
+            /// Form-level error, stored under `FORM_ERROR_KEY`
+            pub form: Option<borang::ValidationError>,
+            /// Errors of keys that aren't form fields, such as field array items
+            pub other: std::collections::HashMap<String, borang::ValidationError>,
+        }
+
+        impl #errors_name {
+            /// True if there is any error, ignoring warnings
+            pub fn has_errors(&self) -> bool {
+                false #(#errors_any)*
+                    || self.form.as_ref().is_some_and(|e| !e.is_warning())
+                    || self.other.values().any(|e| !e.is_warning())
+            }
+        }
+
+        impl From<std::collections::HashMap<String, borang::ValidationError>> for #errors_name {
+            fn from(mut errors: std::collections::HashMap<String, borang::ValidationError>) -> Self {
+                Self {
+                    #(#errors_from_map)*
+                    form: errors.remove(borang::FORM_ERROR_KEY),
+                    other: errors,
+                }
+            }
+        }
+
+        impl #impl_generics borang::FormValidation for #name #ty_generics #where_clause {
             fn validate_all(&self) -> std::collections::HashMap<String, borang::ValidationError> {
                 use borang::FromFieldValue;
 
@@ -633,6 +3787,8 @@ pub fn derive_form_validation(input: TokenStream) -> TokenStream {
 
                 #(#validate_all_calls)*
 
+                #(#form_validator_calls)*
+
                 errors
             }
 
@@ -641,14 +3797,76 @@ pub fn derive_form_validation(input: TokenStream) -> TokenStream {
 
                 match field_name {
                     #(#validate_field_arms,)*
+                    #(#validate_path_arms)*
                     _ => Ok(()),
                 }
             }
 
+            fn validate_field_all(&self, field_name: &str) -> Vec<borang::ValidationError> {
+                use borang::FromFieldValue;
+
+                match field_name {
+                    #(#validate_field_all_arms,)*
+                    #(#validate_path_all_arms)*
+                    _ => Vec::new(),
+                }
+            }
+
             fn field_names() -> Vec<&'static str> {
                 vec![#(#field_names),*]
             }
 
+            fn field_label(field_name: &str) -> Option<&'static str> {
+                match field_name {
+                    #(#field_label_arms)*
+                    #(#path_label_arms)*
+                    _ => None,
+                }
+            }
+
+            fn field_meta() -> Vec<borang::FieldMeta> {
+                vec![#(#field_meta_items),*]
+            }
+
+            fn is_field_required(field_name: &str) -> bool {
+                match field_name {
+                    #(#field_required_arms)*
+                    #(#path_required_arms)*
+                    _ => false,
+                }
+            }
+
+            fn number_bounds(field_name: &str) -> borang::NumberBounds {
+                match field_name {
+                    #(#number_bounds_arms)*
+                    #(#path_number_bounds_arms)*
+                    _ => borang::NumberBounds::default(),
+                }
+            }
+
+            fn text_bounds(field_name: &str) -> borang::TextBounds {
+                match field_name {
+                    #(#text_bounds_arms)*
+                    #(#path_text_bounds_arms)*
+                    _ => borang::TextBounds::default(),
+                }
+            }
+
+            fn field_format(field_name: &str) -> Option<borang::FieldFormat> {
+                match field_name {
+                    #(#field_format_arms)*
+                    #(#path_format_arms)*
+                    _ => None,
+                }
+            }
+
+            fn field_dependents(field_name: &str) -> Vec<&'static str> {
+                match field_name {
+                    #(#field_dependents_arms)*
+                    _ => Vec::new(),
+                }
+            }
+
             fn sync_from_strings(
                 &mut self,
                 fields: &std::collections::HashMap<String, borang::FieldSignal>
@@ -676,3 +3894,254 @@ pub fn derive_form_validation(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Derive macro for implementing `FromFieldValue` on fieldless enums.
+///
+/// Each variant is parsed from and written as its name. Use
+/// `#[field_value = "..."]` on a variant to use a different string. Values that
+/// match no variant are reported as `ErrorKind::NotOneOf`.
+///
+/// `FieldOptions` is implemented too, listing the variants for `Select` and
+/// the other choice components. A variant's label is its name split into
+/// words, e.g. "United Kingdom" for `UnitedKingdom`, or the string given with
+/// `#[field_label = "..."]`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(FromFieldValue, Default, Clone)]
+/// enum Country {
+///     #[field_value = "my"]
+///     Malaysia,
+///     #[field_value = "uk"]
+///     UnitedKingdom,
+///     #[default]
+///     #[field_label = "Somewhere else"]
+///     Other,
+/// }
+/// ```
+#[proc_macro_derive(FromFieldValue, attributes(field_value, field_label))]
+pub fn derive_from_field_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_from_field_value(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Generate the `FromFieldValue` implementation for a fieldless enum
+fn expand_from_field_value(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Enum(data_enum) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "FromFieldValue can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::new();
+    let mut values = Vec::new();
+    let mut labels = Vec::new();
+
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "FromFieldValue can only be derived for enums without variant fields",
+            ));
+        }
+
+        let mut value = variant.ident.to_string();
+        let mut label = variant_label(&variant.ident.to_string());
+        for attr in &variant.attrs {
+            let Some(attr_name) = ["field_value", "field_label"]
+                .into_iter()
+                .find(|name| attr.path().is_ident(name))
+            else {
+                continue;
+            };
+            let Meta::NameValue(nv) = &attr.meta else {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    format!("Expected #[{} = \"...\"]", attr_name),
+                ));
+            };
+            let Expr::Lit(ExprLit {
+                lit: Lit::Str(lit_str),
+                ..
+            }) = &nv.value
+            else {
+                return Err(syn::Error::new_spanned(
+                    &nv.value,
+                    format!("{} must be a string literal", attr_name),
+                ));
+            };
+            if attr_name == "field_value" {
+                value = lit_str.value();
+            } else {
+                label = lit_str.value();
+            }
+        }
+
+        if values.contains(&value) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("Duplicate field value \"{}\"", value),
+            ));
+        }
+
+        variants.push(&variant.ident);
+        values.push(value);
+        labels.push(label);
+    }
+
+    Ok(quote! {
+        impl #impl_generics borang::FromFieldValue for #name #ty_generics #where_clause {
+            fn from_field_value(
+                field_name: &str,
+                value: &str,
+            ) -> Result<Self, borang::ValidationError> {
+                match value {
+                    #(#values => Ok(Self::#variants),)*
+                    _ => Err(borang::ValidationError::with_kind(
+                        borang::ErrorKind::NotOneOf {
+                            field: field_name.to_string(),
+                            allowed: vec![#(#values.to_string()),*],
+                        }
+                    )),
+                }
+            }
+
+            fn to_field_value(&self) -> String {
+                match self {
+                    #(Self::#variants => #values,)*
+                }
+                .to_string()
+            }
+        }
+
+        impl #impl_generics borang::FieldOptions for #name #ty_generics #where_clause {
+            fn field_options() -> Vec<(String, String)> {
+                vec![#((#values.to_string(), #labels.to_string())),*]
+            }
+        }
+    })
+}
+
+/// Split a variant name into words for its label, e.g. `UnitedKingdom` into
+/// "United Kingdom"
+fn variant_label(name: &str) -> String {
+    let mut label = String::new();
+    for (index, c) in name.char_indices() {
+        if index > 0 && c.is_uppercase() && !name[..index].ends_with(char::is_uppercase) {
+            label.push(' ');
+        }
+        label.push(c);
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_pattern_pads_unanchored_patterns() {
+        assert_eq!(html_pattern("^[a-z]+$").as_deref(), Some("^[a-z]+$"));
+        assert_eq!(
+            html_pattern("[0-9]{3}").as_deref(),
+            Some(".*(?:[0-9]{3}).*")
+        );
+        assert_eq!(html_pattern("^a|b$").as_deref(), Some(".*(?:^a|b$).*"));
+        assert_eq!(
+            html_pattern(r"^price\$").as_deref(),
+            Some(r".*(?:^price\$).*")
+        );
+    }
+
+    #[test]
+    fn html_pattern_escapes_class_punctuation_for_the_v_flag() {
+        assert_eq!(html_pattern("^[a-z_-]+$").as_deref(), Some(r"^[a-z_\-]+$"));
+        assert_eq!(
+            html_pattern(r"^[-+]?\d+$").as_deref(),
+            Some(r"^[\-\+]?\d+$")
+        );
+        assert_eq!(
+            html_pattern("^[(){}|/]$").as_deref(),
+            Some(r"^[\(\)\{\}\|\/]$")
+        );
+        assert_eq!(html_pattern("^[.,:;]+$").as_deref(), Some(r"^[\.\,\:\;]+$"));
+        assert_eq!(html_pattern("^[]a]$").as_deref(), Some(r"^[\]a]$"));
+        assert_eq!(html_pattern("^[^]]$").as_deref(), Some(r"^[^\]]$"));
+        assert_eq!(html_pattern("^[a-c-e]$").as_deref(), Some(r"^[a-c\-e]$"));
+        assert_eq!(html_pattern(r"^[\w.-]+$").as_deref(), Some(r"^[\w\.\-]+$"));
+        assert_eq!(html_pattern(r"^[\--/]$").as_deref(), Some(r"^[\--\/]$"));
+    }
+
+    #[test]
+    fn html_pattern_rewrites_escapes_javascript_rejects() {
+        assert_eq!(html_pattern(r"^a\-b\#c$").as_deref(), Some("^a-b#c$"));
+        assert_eq!(html_pattern(r"^\.\/\x41$").as_deref(), Some(r"^\.\/\x41$"));
+        assert_eq!(
+            html_pattern(r"^(?P<year>\d{4})$").as_deref(),
+            Some(r"^(?<year>\d{4})$")
+        );
+    }
+
+    #[test]
+    fn html_pattern_skips_constructs_javascript_lacks() {
+        for pattern in [
+            "(?i)^abc$",
+            r"\Aabc\z",
+            r"^\pL+$",
+            r"^\p{Greek}+$",
+            "^[[:alpha:]]+$",
+            "^[a-z&&[^aeiou]]+$",
+            "^[a-z--b]$",
+            r"^\x{1F600}$",
+            r"\bword\b{end}",
+            r"\<word\>",
+            "^[a-z",
+        ] {
+            assert_eq!(html_pattern(pattern), None, "{}", pattern);
+        }
+    }
+
+    fn decimal_parts(digits: &str) -> Option<(i128, u32)> {
+        LitDigits {
+            digits: digits.to_string(),
+            span: proc_macro2::Span::call_site(),
+        }
+        .decimal_parts()
+    }
+
+    #[test]
+    fn decimal_parts_reads_mantissa_and_scale() {
+        assert_eq!(decimal_parts("0.1"), Some((1, 1)));
+        assert_eq!(decimal_parts("-12.50"), Some((-1250, 2)));
+        assert_eq!(decimal_parts("1e-3"), Some((1, 3)));
+        assert_eq!(decimal_parts("1.5E2"), Some((150, 0)));
+        assert_eq!(decimal_parts("0.000"), Some((0, 0)));
+        assert_eq!(decimal_parts("0e-40"), Some((0, 0)));
+    }
+
+    #[test]
+    fn decimal_parts_keeps_to_decimal_limits() {
+        // Trailing zeros past 28 places are dropped
+        let ten_to_28 = 10_i128.pow(28);
+        assert_eq!(
+            decimal_parts(&format!("1.{}", "0".repeat(30))),
+            Some((ten_to_28, 28))
+        );
+        assert_eq!(decimal_parts("1e-29"), None);
+        // The mantissa must fit in 96 bits
+        assert_eq!(
+            decimal_parts("79228162514264337593543950335"),
+            Some(((1 << 96) - 1, 0))
+        );
+        assert_eq!(decimal_parts("79228162514264337593543950336"), None);
+        assert_eq!(decimal_parts("1e400"), None);
+    }
+}
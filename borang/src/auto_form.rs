@@ -0,0 +1,151 @@
+use leptos::prelude::*;
+
+use crate::{
+    form::{use_form, Form},
+    validation::{FieldMeta, FormValidation},
+    Checkbox, ColorInput, DateInput, ErrorMessage, Field, FieldState, FileInput, Input, Label,
+    MultiSelect, NumberInput, PasswordInput, Select,
+};
+
+/// AutoForm component that renders a default input for every field of a form
+///
+/// Each field from `FormValidation::field_meta` gets a `Label`, an input
+/// picked from its type and validators, and an `ErrorMessage`, wrapped in a
+/// `<div>`. Fields with a `one_of` validator get a `Select`, or a
+/// `MultiSelect` for `Vec` fields, `bool` fields a `Checkbox`, numbers
+/// (including `Decimal`) a `NumberInput`, `chrono` dates a `DateInput`, `FileInfo` fields a
+/// `FileInput`, `HexColor` fields and `hex_color` fields a `ColorInput`, and
+/// `password` fields a `PasswordInput`. Everything else is an `Input`, of type
+/// `url` for `url::Url` fields and `email` for `email` fields. Nested and
+/// array fields are left out.
+///
+/// This is meant for admin screens and prototypes. The form element and
+/// submit button stay with the caller.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <form on:submit=on_submit>
+///         <FormComponent form=form let:_form_state>
+///             <AutoForm<ContactForm> field_class="field" input_class="input" />
+///             <button type="submit">"Save"</button>
+///         </FormComponent>
+///     </form>
+/// }
+/// ```
+#[component]
+pub fn AutoForm<T>(
+    /// The form to render (defaults to the form of the enclosing
+    /// `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
+    /// Class of the element wrapping each field
+    #[prop(into, optional)]
+    field_class: &'static str,
+    #[prop(into, optional)] label_class: &'static str,
+    #[prop(into, optional)] input_class: &'static str,
+    #[prop(into, optional)] error_class: &'static str,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let form = form.unwrap_or_else(use_form::<T>);
+    T::field_meta()
+        .into_iter()
+        .filter(|meta| !meta.compound)
+        .map(|meta| {
+            let name = meta.name;
+            view! {
+                <Field form=form name=name let:field_state>
+                    <div class=field_class>
+                        <Label state=field_state.clone() class=label_class />
+                        {auto_input(field_state.clone(), &meta, input_class)}
+                        <ErrorMessage state=field_state class=error_class />
+                    </div>
+                </Field>
+            }
+        })
+        .collect_view()
+}
+
+/// Render the default input for a field
+fn auto_input<T>(state: FieldState<T>, meta: &FieldMeta, class: &'static str) -> AnyView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let has_validator = |validator: &str| meta.validators.iter().any(|v| v.name == validator);
+    let allowed: Vec<String> = meta
+        .validators
+        .iter()
+        .filter(|validator| validator.name == "one_of")
+        .flat_map(|validator| validator.params.iter().map(|(_, value)| value.clone()))
+        .collect();
+    let options = move || {
+        allowed
+            .into_iter()
+            .map(|value| {
+                let label = value.clone();
+                view! { <option value=value>{label}</option> }
+            })
+            .collect_view()
+    };
+
+    let type_name = unwrap_type(meta.type_name, "Option");
+    let item_type = unwrap_type(type_name, "Vec");
+    let is_vec = item_type != type_name;
+    let item_type = item_type.rsplit("::").next().unwrap_or(item_type);
+
+    match item_type {
+        "FileInfo" => view! { <FileInput state=state class=class multiple=is_vec /> }.into_any(),
+        _ if has_validator("one_of") && is_vec => view! {
+            <MultiSelect state=state class=class>
+                {options()}
+            </MultiSelect>
+        }
+        .into_any(),
+        _ if has_validator("one_of") => view! {
+            <Select state=state class=class>
+                <option value=""></option>
+                {options()}
+            </Select>
+        }
+        .into_any(),
+        "HexColor" => view! { <ColorInput state=state class=class /> }.into_any(),
+        _ if has_validator("hex_color") => {
+            view! { <ColorInput state=state class=class /> }.into_any()
+        }
+        "bool" => view! { <Checkbox state=state class=class /> }.into_any(),
+        "NaiveDate" => view! { <DateInput state=state class=class /> }.into_any(),
+        "NaiveDateTime" => {
+            view! { <DateInput state=state class=class with_time=true /> }.into_any()
+        }
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" | "f32" | "f64" | "Decimal" => {
+            view! { <NumberInput state=state class=class /> }.into_any()
+        }
+        non_zero if non_zero.starts_with("NonZero") => {
+            view! { <NumberInput state=state class=class /> }.into_any()
+        }
+        "Url" => view! { <Input state=state class=class input_type="url" /> }.into_any(),
+        _ if has_validator("password") => {
+            view! { <PasswordInput state=state input_class=class /> }.into_any()
+        }
+        _ => {
+            let input_type = if has_validator("email") {
+                "email"
+            } else {
+                "text"
+            };
+            view! { <Input state=state class=class input_type=input_type /> }.into_any()
+        }
+    }
+}
+
+/// Get the type inside a wrapper, e.g. `u32` for `Option<u32>`
+pub(crate) fn unwrap_type<'a>(type_name: &'a str, wrapper: &str) -> &'a str {
+    type_name
+        .split_once(&format!("{}<", wrapper))
+        .filter(|(path, _)| path.is_empty() || path.ends_with("::"))
+        .and_then(|(_, inner)| inner.strip_suffix('>'))
+        .unwrap_or(type_name)
+}
@@ -0,0 +1,55 @@
+use leptos::prelude::*;
+
+use crate::{
+    validation::{FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// Checkbox component for `bool` form fields.
+///
+/// This component binds the checkbox's checked state, rather than its
+/// value, to the FieldState, writing `"true"` or `"false"` to the field on
+/// every `change` event, and sets up mark touched on blur. It sets the same
+/// ARIA attributes as `Input`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="subscribe" let:field_state>
+///         <Checkbox state=field_state.clone() class="checkbox-class" />
+///         <Label state=field_state />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn Checkbox<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let checked = {
+        let name = state.field_name.clone();
+        move || bool::from_field_value(&name, &value.get()).unwrap_or(false)
+    };
+    view! {
+        <input
+            id=id
+            type="checkbox"
+            prop:checked=checked
+            on:change=move |ev| value.set(event_target_checked(&ev).to_field_value())
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
@@ -0,0 +1,105 @@
+use leptos::prelude::*;
+
+use crate::{
+    validation::{FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// CheckboxGroup component for `Vec<String>` form fields.
+///
+/// This component renders a checkbox per option, given as `(value, label)`
+/// pairs, and keeps the field's value as the comma-separated list of checked
+/// values that `Vec<String>` fields parse, with commas in option values
+/// escaped. It marks the field touched when a checkbox loses focus, and sets
+/// the same ARIA attributes as `Input` on the group.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="interests" let:field_state>
+///         <CheckboxGroup
+///             state=field_state
+///             options=vec![
+///                 ("music".to_string(), "Music".to_string()),
+///                 ("sports".to_string(), "Sports".to_string()),
+///             ]
+///             class="group-class"
+///             option_class="option-class"
+///         />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn CheckboxGroup<T>(
+    state: FieldState<T>,
+    /// Id of the group `<div>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// The checkboxes to render, as `(value, label)` pairs
+    #[prop(into)]
+    options: Vec<(String, String)>,
+    #[prop(into, optional)] class: &'static str,
+    /// Class for the `<label>` wrapping each checkbox
+    #[prop(into, optional)]
+    option_class: &'static str,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+
+    let checkboxes = options
+        .into_iter()
+        .map(|(option, label)| {
+            let state = state.clone();
+            let selected = {
+                let name = state.field_name.clone();
+                move |value: &str| Vec::<String>::from_field_value(&name, value).unwrap_or_default()
+            };
+            let checked = {
+                let option = option.clone();
+                let selected = selected.clone();
+                move || selected(&value.get()).contains(&option)
+            };
+            let toggle = {
+                let option = option.clone();
+                move |ev: leptos::ev::Event| {
+                    let mut values = selected(&value.get_untracked());
+                    values.retain(|value| *value != option);
+                    if event_target_checked(&ev) {
+                        values.push(option.clone());
+                    }
+                    value.set(values.to_field_value());
+                }
+            };
+            view! {
+                <label class=option_class>
+                    <input
+                        type="checkbox"
+                        name=state.field_name.clone()
+                        value=option
+                        prop:checked=checked
+                        on:change=toggle
+                        on:blur=move |_| state.mark_touched()
+                    />
+                    {label}
+                </label>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div
+            id=id
+            role="group"
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+        >
+            {checkboxes}
+        </div>
+    }
+}
@@ -0,0 +1,50 @@
+use leptos::prelude::*;
+
+use crate::{validation::FormValidation, FieldState};
+
+/// ColorInput component for color form fields.
+///
+/// This component renders an `<input type="color">` bound like `Input`, for
+/// `HexColor` fields or `String` fields with a `hex_color` validator. Browsers
+/// report the picked color as lowercase `#rrggbb`, and show black while the
+/// field is empty.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="accent" let:field_state>
+///         <Label state=field_state.clone() />
+///         <ColorInput state=field_state class="color-input" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn ColorInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    view! {
+        <input
+            type="color"
+            id=id
+            disabled=move || disabled.get().unwrap_or(false)
+            prop:value=move || value.get()
+            on:input=move |ev| value.set(event_target_value(&ev))
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
@@ -0,0 +1,233 @@
+use std::future::Future;
+
+use leptos::prelude::*;
+
+use crate::{
+    validation::{FormValidation, ValidationError},
+    FieldState,
+};
+
+/// Combobox component for picking a field's value from suggestions.
+///
+/// This component renders a text input with a `role="listbox"` list of
+/// suggestions below it. The `fetch` function is called with the typed text on
+/// every input and returns the suggestions as `(value, label)` pairs, usually
+/// from a server. Responses that arrive after a newer one was requested are
+/// dropped. Choosing a suggestion, with a click or the arrow keys and Enter,
+/// writes its value into the field and shows its label in the input. Escape
+/// closes the list.
+///
+/// Typed text is written into the field as it is. With `strict`, text that
+/// was not chosen from the suggestions is rejected with `Form::reject_value`,
+/// so the field fails validation with the `free_text` code until a suggestion
+/// is chosen or the input is cleared.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="city" let:field_state>
+///         <Label state=field_state.clone() />
+///         <Combobox
+///             state=field_state
+///             fetch=|query: String| async move { search_cities(query).await.unwrap_or_default() }
+///             strict=true
+///             list_class="suggestions"
+///         />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn Combobox<T, F, Fut>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// Loads the `(value, label)` suggestions for the typed text
+    fetch: F,
+    /// Class of the element wrapping the input and the list
+    #[prop(into, optional)]
+    class: &'static str,
+    #[prop(into, optional)] input_class: &'static str,
+    #[prop(into, optional)] list_class: &'static str,
+    #[prop(into, optional)] option_class: &'static str,
+    #[prop(optional)] placeholder: Option<&'static str>,
+    /// Reject typed text that was not chosen from the suggestions
+    #[prop(optional)]
+    strict: bool,
+    /// Error message for rejected text (defaults to "{label} is not valid")
+    #[prop(optional)]
+    free_text_message: Option<&'static str>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+    F: Fn(String) -> Fut + 'static,
+    Fut: Future<Output = Vec<(String, String)>> + 'static,
+{
+    let form = state.form;
+    let name = StoredValue::new(state.field_name.clone());
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let text = RwSignal::new(value.get_untracked());
+    let chosen = RwSignal::new(None::<(String, String)>);
+    let suggestions = RwSignal::new(Vec::<(String, String)>::new());
+    let open = RwSignal::new(false);
+    let highlighted = RwSignal::new(None::<usize>);
+    let fetch = StoredValue::new_local(fetch);
+    let request = StoredValue::new(0u64);
+    let listbox_id = format!("{}-listbox", state.field_name);
+    let option_id =
+        move |index: usize| name.with_value(|name| format!("{}-option-{}", name, index));
+
+    // Show the field's value when it changes from outside, e.g. on reset
+    Effect::new(move |_| {
+        let current = value.get();
+        let is_chosen = chosen
+            .with_untracked(|chosen| chosen.as_ref().is_some_and(|(value, _)| *value == current));
+        if !is_chosen && text.get_untracked() != current {
+            chosen.set(None);
+            text.set(current);
+        }
+    });
+
+    let load = move |query: String| {
+        request.update_value(|request| *request += 1);
+        let id = request.get_value();
+        let future = fetch.with_value(|fetch| fetch(query));
+        leptos::task::spawn_local(async move {
+            let options = future.await;
+            if request.get_value() == id {
+                highlighted.set(None);
+                suggestions.set(options);
+                open.set(true);
+            }
+        });
+    };
+    let choose = move |index: usize| {
+        let option = suggestions.with_untracked(|options| options.get(index).cloned());
+        if let Some((option_value, label)) = option {
+            name.with_value(|name| form.clear_rejected_value(name));
+            chosen.set(Some((option_value.clone(), label.clone())));
+            text.set(label);
+            value.set(option_value);
+            open.set(false);
+            highlighted.set(None);
+        }
+    };
+
+    let label = state.label().to_string();
+    let on_input = move |ev| {
+        let typed = event_target_value(&ev);
+        chosen.set(None);
+        text.set(typed.clone());
+        if strict && !typed.is_empty() {
+            let name = name.get_value();
+            let error = ValidationError::custom_code(name.as_str(), "free_text").with_label(&label);
+            let error = match free_text_message {
+                Some(message) => error.with_message(message),
+                None => error,
+            };
+            form.reject_value(&name, typed.clone(), error);
+        } else {
+            name.with_value(|name| form.clear_rejected_value(name));
+        }
+        value.set(typed.clone());
+        load(typed);
+    };
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let count = suggestions.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                highlighted.update(|index| {
+                    *index = Some(index.map_or(0, |index| (index + 1) % count));
+                });
+            }
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                highlighted.update(|index| {
+                    *index = Some(index.map_or(count - 1, |index| (index + count - 1) % count));
+                });
+            }
+            "Enter" if open.get_untracked() => {
+                if let Some(index) = highlighted.get_untracked() {
+                    ev.prevent_default();
+                    choose(index);
+                }
+            }
+            "Escape" if open.get_untracked() => {
+                ev.prevent_default();
+                open.set(false);
+                highlighted.set(None);
+            }
+            _ => {}
+        }
+    };
+    let on_blur = {
+        let state = state.clone();
+        move |_| {
+            open.set(false);
+            highlighted.set(None);
+            state.mark_touched();
+        }
+    };
+
+    view! {
+        <div class=class>
+            <input
+                type="text"
+                id=id
+                role="combobox"
+                autocomplete="off"
+                placeholder=placeholder
+                aria-autocomplete="list"
+                aria-controls=listbox_id.clone()
+                aria-expanded=move || open.get().to_string()
+                aria-activedescendant=move || highlighted.get().map(option_id)
+                prop:value=move || text.get()
+                on:input=on_input
+                on:keydown=on_keydown
+                class=input_class
+                aria-required=state.is_required().then_some("true")
+                aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+                aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+                on:blur=on_blur
+            />
+            <ul
+                id=listbox_id
+                role="listbox"
+                class=list_class
+                hidden=move || !open.get() || suggestions.with(Vec::is_empty)
+            >
+                {move || {
+                    suggestions
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, (_, label))| {
+                            view! {
+                                <li
+                                    id=option_id(index)
+                                    role="option"
+                                    class=option_class
+                                    aria-selected=move || {
+                                        (highlighted.get() == Some(index)).to_string()
+                                    }
+                                    on:mousedown=move |ev| {
+                                        // Keep the focus in the input until the option is chosen
+                                        ev.prevent_default();
+                                        choose(index);
+                                    }
+                                >
+                                    {label}
+                                </li>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
@@ -0,0 +1,430 @@
+use leptos::prelude::*;
+
+use crate::{validation::FormValidation, FieldState};
+
+/// CurrencyInput component for money form fields.
+///
+/// This component renders a text input that shows the field's amount
+/// formatted for the user's locale, e.g. `1,234.50` or `1.234,50`, while the
+/// field holds the canonical number, e.g. `1234.5`, that `f64` and other
+/// numeric fields parse. Use a `rust_decimal::Decimal` field (with the
+/// `decimal` feature) to keep amounts exact. Thousand separators, the prefix
+/// and the suffix are ignored in typed text, and the decimal separator is read
+/// as a `.`. The
+/// amount is formatted when the input loses focus or the field changes from
+/// outside, with the fraction padded to `decimals` digits. Text that isn't a
+/// number is kept as typed, so the field reports its parse error.
+///
+/// With a `locale`, the separators come from the browser's
+/// `Intl.NumberFormat` for that locale, and with a `currency` too, so do the
+/// symbol, which side of the amount it goes on, and the number of decimals.
+/// The separator, prefix, suffix and `decimals` props override what the
+/// locale gives. Outside the browser, e.g. when rendering on the server, the
+/// locale is ignored until the input is hydrated.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="price" let:field_state>
+///         <CurrencyInput state=field_state prefix="RM " />
+///     </Field>
+///     <Field form=form name="preis" let:field_state>
+///         <CurrencyInput state=field_state decimal_separator=',' suffix=" €" />
+///     </Field>
+///     <Field form=form name="betrag" let:field_state>
+///         <CurrencyInput state=field_state locale="de-DE" currency="EUR" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn CurrencyInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    /// Locale whose separators amounts are shown with, e.g. `"de-DE"`
+    #[prop(optional)]
+    locale: Option<&'static str>,
+    /// ISO 4217 code of the currency, e.g. `"EUR"`, whose symbol is shown
+    /// where `locale` puts it
+    #[prop(optional)]
+    currency: Option<&'static str>,
+    /// Text shown before the amount, such as a currency symbol (defaults to
+    /// the locale's)
+    #[prop(optional)]
+    prefix: Option<&'static str>,
+    /// Text shown after the amount (defaults to the locale's)
+    #[prop(optional)]
+    suffix: Option<&'static str>,
+    /// Separator between the whole and fractional parts (defaults to the
+    /// locale's, or `.`)
+    #[prop(optional)]
+    decimal_separator: Option<char>,
+    /// Separator between groups of thousands (defaults to the locale's, or `,`,
+    /// or `.` when the decimal separator is `,`)
+    #[prop(optional)]
+    group_separator: Option<char>,
+    /// Digits shown after the decimal separator (defaults to the currency's,
+    /// or 2)
+    #[prop(optional)]
+    decimals: Option<usize>,
+    #[prop(optional)] placeholder: Option<&'static str>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let locale = locale
+        .and_then(|locale| locale_format(locale, currency))
+        .unwrap_or_default();
+    let decimal_separator = decimal_separator
+        .or(locale.decimal_separator)
+        .unwrap_or('.');
+    let format = StoredValue::new(AmountFormat {
+        prefix: prefix.map_or(locale.prefix, str::to_string),
+        suffix: suffix.map_or(locale.suffix, str::to_string),
+        decimal_separator,
+        group_separator: group_separator
+            .or(locale
+                .group_separator
+                .filter(|group| *group != decimal_separator))
+            .unwrap_or(if decimal_separator == ',' { '.' } else { ',' }),
+        decimals: decimals.or(locale.decimals).unwrap_or(2),
+    });
+    let display = move |amount: &str| {
+        format
+            .with_value(|format| format.format(amount))
+            .unwrap_or_else(|| amount.to_string())
+    };
+
+    let text = RwSignal::new(display(&value.get_untracked()));
+    let focused = StoredValue::new(false);
+
+    // Format amounts set from outside, but leave the text being typed alone
+    Effect::new(move |_| {
+        let amount = value.get();
+        if !focused.get_value() {
+            text.set(display(&amount));
+        }
+    });
+
+    view! {
+        <input
+            id=id
+            type="text"
+            inputmode="decimal"
+            placeholder=placeholder
+            prop:value=move || text.get()
+            on:focus=move |_| focused.set_value(true)
+            on:input=move |ev| {
+                let typed = event_target_value(&ev);
+                value.set(format.with_value(|format| format.parse(&typed)));
+                text.set(typed);
+            }
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| {
+                focused.set_value(false);
+                text.set(display(&value.get_untracked()));
+                state.mark_touched();
+            }
+        />
+    }
+}
+
+/// How `CurrencyInput` shows amounts
+struct AmountFormat {
+    prefix: String,
+    suffix: String,
+    decimal_separator: char,
+    group_separator: char,
+    decimals: usize,
+}
+
+impl AmountFormat {
+    /// Get the canonical amount of typed text, e.g. `-1234.5` for
+    /// `RM -1,234.5`
+    fn parse(&self, text: &str) -> String {
+        let text = text.trim();
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(text) => ("-", text),
+            None => ("", text),
+        };
+        let text = text.strip_prefix(self.prefix.trim()).unwrap_or(text);
+        let text = text.strip_suffix(self.suffix.trim()).unwrap_or(text);
+        let amount: String = text
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != self.group_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+        format!("{}{}", sign, amount)
+    }
+
+    /// Format a canonical amount, or `None` if it isn't a plain decimal
+    /// number
+    ///
+    /// The digits are kept as they are, so amounts too precise for `f64`
+    /// don't lose digits.
+    fn format(&self, amount: &str) -> Option<String> {
+        if amount.is_empty() {
+            return Some(String::new());
+        }
+        let (sign, digits) = match amount.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", amount),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if whole.len() + fraction.len() == 0 || !is_digits(whole) || !is_digits(fraction) {
+            return None;
+        }
+        let whole = if whole.is_empty() { "0" } else { whole };
+
+        let mut formatted = format!("{}{}", sign, self.prefix);
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0 && (whole.len() - index) % 3 == 0 {
+                formatted.push(self.group_separator);
+            }
+            formatted.push(digit);
+        }
+        if self.decimals > 0 || !fraction.is_empty() {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+            for _ in fraction.len()..self.decimals {
+                formatted.push('0');
+            }
+        }
+        formatted.push_str(&self.suffix);
+        Some(formatted)
+    }
+}
+
+/// How a locale writes amounts, as far as `Intl.NumberFormat` tells
+#[derive(Debug, Default, PartialEq)]
+struct LocaleFormat {
+    decimal_separator: Option<char>,
+    group_separator: Option<char>,
+    prefix: String,
+    suffix: String,
+    /// Digits after the decimal separator, only known for a currency
+    decimals: Option<usize>,
+}
+
+impl LocaleFormat {
+    /// Read the format from the `(type, value)` parts `formatToParts` splits
+    /// an amount into
+    ///
+    /// Parts before the number make the prefix and parts after it the suffix,
+    /// e.g. `"RM"` and `" "` for `RM 1,234,567.50`.
+    fn from_parts(parts: &[(String, String)], currency: bool) -> Self {
+        let is_number = |kind: &str| matches!(kind, "integer" | "group" | "decimal" | "fraction");
+        let first = parts.iter().position(|(kind, _)| is_number(kind));
+        let last = parts.iter().rposition(|(kind, _)| is_number(kind));
+        let mut format = LocaleFormat::default();
+        for (index, (kind, value)) in parts.iter().enumerate() {
+            match kind.as_str() {
+                "decimal" => format.decimal_separator = value.chars().next(),
+                "group" => format.group_separator = value.chars().next(),
+                "fraction" if currency => format.decimals = Some(value.len()),
+                "minusSign" | "plusSign" => {}
+                _ if first.is_some_and(|first| index < first) => format.prefix.push_str(value),
+                _ if last.is_some_and(|last| index > last) => format.suffix.push_str(value),
+                _ => {}
+            }
+        }
+        if currency && format.decimals.is_none() {
+            format.decimals = Some(0);
+        }
+        format
+    }
+}
+
+/// Get how `locale` writes amounts, with `currency`'s symbol if given, or
+/// `None` outside the browser or for a locale or currency `Intl` rejects
+fn locale_format(locale: &str, currency: Option<&str>) -> Option<LocaleFormat> {
+    let mut format = LocaleFormat::from_parts(&number_parts(locale, currency)?, currency.is_some());
+    // Currencies without minor units, like JPY, show no decimal separator
+    if format.decimal_separator.is_none() {
+        let parts = number_parts(locale, None)?;
+        format.decimal_separator = LocaleFormat::from_parts(&parts, false).decimal_separator;
+    }
+    Some(format)
+}
+
+/// Get the `(type, value)` parts `Intl.NumberFormat` splits an amount into
+/// for `locale`, formatted as `currency` if given
+#[cfg(target_arch = "wasm32")]
+fn number_parts(locale: &str, currency: Option<&str>) -> Option<Vec<(String, String)>> {
+    use web_sys::js_sys::{self, Array, Function, Intl, Object, Reflect};
+    use web_sys::wasm_bindgen::JsCast;
+
+    let options = Object::new();
+    if let Some(currency) = currency {
+        Reflect::set(&options, &"style".into(), &"currency".into()).ok()?;
+        Reflect::set(&options, &"currency".into(), &currency.into()).ok()?;
+    }
+    // Construct through `Reflect` to get the `RangeError` thrown for an
+    // invalid locale or currency as an `Err`
+    let intl = Reflect::get(&js_sys::global(), &"Intl".into()).ok()?;
+    let constructor = Reflect::get(&intl, &"NumberFormat".into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    let format = Reflect::construct(&constructor, &Array::of2(&locale.into(), &options))
+        .ok()?
+        .unchecked_into::<Intl::NumberFormat>();
+    format
+        .format_to_parts(1_234_567.5)
+        .iter()
+        .map(|part| {
+            let field = |name: &str| Reflect::get(&part, &name.into()).ok()?.as_string();
+            Some((field("type")?, field("value")?))
+        })
+        .collect()
+}
+
+/// Get the parts of an amount, which needs the browser's `Intl`
+#[cfg(not(target_arch = "wasm32"))]
+fn number_parts(_locale: &str, _currency: Option<&str>) -> Option<Vec<(String, String)>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(
+        prefix: &str,
+        suffix: &str,
+        decimal_separator: char,
+        group_separator: char,
+    ) -> AmountFormat {
+        AmountFormat {
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+            decimal_separator,
+            group_separator,
+            decimals: 2,
+        }
+    }
+
+    fn parts(parts: &[(&str, &str)]) -> Vec<(String, String)> {
+        parts
+            .iter()
+            .map(|(kind, value)| (kind.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn formats_amounts_with_groups_and_padded_fraction() {
+        let ringgit = format("RM ", "", '.', ',');
+        assert_eq!(
+            ringgit.format("1234567.5").as_deref(),
+            Some("RM 1,234,567.50")
+        );
+        assert_eq!(ringgit.format("-12").as_deref(), Some("-RM 12.00"));
+        assert_eq!(ringgit.format(".5").as_deref(), Some("RM 0.50"));
+        assert_eq!(ringgit.format("").as_deref(), Some(""));
+        assert_eq!(ringgit.format("12a"), None);
+        assert_eq!(ringgit.format("-"), None);
+
+        let euro = format("", " €", ',', '.');
+        assert_eq!(euro.format("1234.5").as_deref(), Some("1.234,50 €"));
+    }
+
+    #[test]
+    fn keeps_digits_too_precise_for_f64() {
+        let plain = format("", "", '.', ',');
+        assert_eq!(
+            plain.format("12345678901234567890.123").as_deref(),
+            Some("12,345,678,901,234,567,890.123")
+        );
+    }
+
+    #[test]
+    fn parses_typed_text_into_canonical_amounts() {
+        let ringgit = format("RM ", "", '.', ',');
+        assert_eq!(ringgit.parse("RM 1,234.50"), "1234.50");
+        assert_eq!(ringgit.parse(" -RM 1,234.5 "), "-1234.5");
+        assert_eq!(ringgit.parse("abc"), "abc");
+
+        let euro = format("", " €", ',', '.');
+        assert_eq!(euro.parse("1.234,50 €"), "1234.50");
+        assert_eq!(euro.parse("1 234,5"), "1234.5");
+    }
+
+    #[test]
+    fn reads_currency_position_and_separators_from_parts() {
+        let ringgit = LocaleFormat::from_parts(
+            &parts(&[
+                ("currency", "RM"),
+                ("literal", "\u{a0}"),
+                ("integer", "1"),
+                ("group", ","),
+                ("integer", "234"),
+                ("group", ","),
+                ("integer", "567"),
+                ("decimal", "."),
+                ("fraction", "50"),
+            ]),
+            true,
+        );
+        assert_eq!(
+            ringgit,
+            LocaleFormat {
+                decimal_separator: Some('.'),
+                group_separator: Some(','),
+                prefix: "RM\u{a0}".to_string(),
+                suffix: String::new(),
+                decimals: Some(2),
+            }
+        );
+
+        let euro = LocaleFormat::from_parts(
+            &parts(&[
+                ("integer", "1"),
+                ("group", "."),
+                ("integer", "234"),
+                ("group", "."),
+                ("integer", "567"),
+                ("decimal", ","),
+                ("fraction", "50"),
+                ("literal", "\u{a0}"),
+                ("currency", "€"),
+            ]),
+            true,
+        );
+        assert_eq!(euro.prefix, "");
+        assert_eq!(euro.suffix, "\u{a0}€");
+        assert_eq!(euro.decimal_separator, Some(','));
+        assert_eq!(euro.group_separator, Some('.'));
+    }
+
+    #[test]
+    fn reads_zero_decimals_for_currencies_without_minor_units() {
+        let yen = LocaleFormat::from_parts(
+            &parts(&[
+                ("currency", "¥"),
+                ("integer", "1"),
+                ("group", ","),
+                ("integer", "234"),
+                ("group", ","),
+                ("integer", "568"),
+            ]),
+            true,
+        );
+        assert_eq!(yen.decimals, Some(0));
+        assert_eq!(yen.decimal_separator, None);
+
+        let plain = LocaleFormat::from_parts(
+            &parts(&[("integer", "1234567"), ("decimal", "."), ("fraction", "5")]),
+            false,
+        );
+        assert_eq!(plain.decimals, None);
+    }
+}
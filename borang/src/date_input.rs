@@ -0,0 +1,65 @@
+use leptos::prelude::*;
+
+use crate::{form::BindOn, validation::FormValidation, FieldState};
+
+/// DateInput component for date form fields.
+///
+/// This component renders an `<input type="date">` bound like `Input`, or an
+/// `<input type="datetime-local">` with `with_time`. Browsers report these as
+/// `YYYY-MM-DD` and `YYYY-MM-DDTHH:MM`, the formats `chrono::NaiveDate` and
+/// `chrono::NaiveDateTime` fields parse with the `chrono` feature enabled.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="birthday" let:field_state>
+///         <DateInput state=field_state class="input-class" />
+///     </Field>
+///     <Field form=form name="starts_at" let:field_state>
+///         <DateInput state=field_state with_time=true />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn DateInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    #[prop(optional, into)] bind_on: Option<BindOn>,
+    /// Include the time of day, with `type="datetime-local"`
+    #[prop(optional)]
+    with_time: bool,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bind_on = bind_on.unwrap_or(state.bind_on);
+    let input_type = if with_time { "datetime-local" } else { "date" };
+    view! {
+        <input
+            id=id
+            type=input_type
+            prop:value=move || value.get()
+            on:input=move |ev| {
+                if bind_on == BindOn::Input {
+                    value.set(event_target_value(&ev));
+                }
+            }
+            on:change=move |ev| {
+                if bind_on == BindOn::Change {
+                    value.set(event_target_value(&ev));
+                }
+            }
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
@@ -0,0 +1,599 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::form::Form;
+use crate::rules::{self, ValidationRule};
+use crate::validation::{
+    ErrorKind, FieldSignal, FormValidation, FromFieldValue, ValidationError, ValidationResult,
+};
+
+/// The type of a schema field's value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FieldType {
+    /// Free text
+    #[default]
+    Text,
+    /// A whole number
+    Integer,
+    /// A decimal number
+    Float,
+    /// A checkbox
+    Boolean,
+}
+
+/// A validator of a schema field, mirroring the derive's `#[validator(...)]`
+/// options
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "snake_case")
+)]
+pub enum SchemaValidator {
+    /// The value must not be empty, or a checkbox must be checked
+    Required,
+    /// The value must be an email address
+    Email,
+    /// The text length must be within bounds, inclusive
+    Length {
+        /// Minimum length
+        min: Option<usize>,
+        /// Maximum length
+        max: Option<usize>,
+    },
+    /// A number must be within bounds, inclusive
+    Range {
+        /// Minimum value
+        min: Option<f64>,
+        /// Maximum value
+        max: Option<f64>,
+    },
+    /// The text must match a regular expression
+    ///
+    /// The expression is compiled once per schema. A field with an invalid
+    /// expression fails with the `invalid_pattern` code; see
+    /// `FormSchema::check_patterns`.
+    Pattern {
+        /// The regular expression
+        pattern: String,
+    },
+    /// The text must be one of the given values
+    OneOf {
+        /// The allowed values
+        values: Vec<String>,
+    },
+}
+
+/// A field of a `FormSchema`
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaField {
+    /// The field's key, used as the `name` of its `Field`
+    pub name: String,
+    /// Human-friendly name used in error messages
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub label: Option<String>,
+    /// The type the field's value is parsed into
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub field_type: FieldType,
+    /// Validators run in order, stopping at the first error
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub validators: Vec<SchemaValidator>,
+}
+
+impl SchemaField {
+    /// Create a field without validators
+    pub fn new(name: impl Into<String>, field_type: FieldType) -> Self {
+        Self {
+            name: name.into(),
+            field_type,
+            ..Self::default()
+        }
+    }
+
+    /// Set the field's label
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Add a validator
+    pub fn with_validator(mut self, validator: SchemaValidator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Run one validator against the field's value
+    fn check(
+        &self,
+        validator: &SchemaValidator,
+        value: &DynamicValue,
+        patterns: &Patterns,
+    ) -> ValidationResult {
+        let name = self.name.as_str();
+        let text = value.to_field_value();
+        match validator {
+            SchemaValidator::Required => match value {
+                DynamicValue::Boolean(false) => {
+                    Err(ValidationError::with_kind(ErrorKind::Required {
+                        field: name.to_string(),
+                    }))
+                }
+                _ => ValidationRule::<str>::validate(&rules::Required, name, &text),
+            },
+            SchemaValidator::Email => ValidationRule::<str>::validate(&rules::Email, name, &text),
+            SchemaValidator::Length { min, max } => {
+                let length = rules::Length {
+                    min: *min,
+                    max: *max,
+                    equal: None,
+                };
+                ValidationRule::<str>::validate(&length, name, &text)
+            }
+            SchemaValidator::Range { min, max } => match value.as_f64() {
+                Some(number) => rules::Range {
+                    min: *min,
+                    max: *max,
+                }
+                .validate(name, &number),
+                None => Ok(()),
+            },
+            SchemaValidator::Pattern { pattern } => {
+                let regex = match patterns.get(pattern) {
+                    Some(Ok(regex)) => regex,
+                    // Fail the field rather than let a broken schema pass or
+                    // panic; `FormSchema::check_patterns` reports the cause
+                    _ => {
+                        return Err(ValidationError::custom_code(name, "invalid_pattern")
+                            .with_param("pattern", pattern.clone()));
+                    }
+                };
+                if !text.is_empty() && !regex.is_match(&text) {
+                    return Err(ValidationError::with_kind(ErrorKind::PatternMismatch {
+                        field: name.to_string(),
+                        pattern: pattern.clone(),
+                    }));
+                }
+                Ok(())
+            }
+            SchemaValidator::OneOf { values } => {
+                if !text.is_empty() && !values.contains(&text) {
+                    return Err(ValidationError::with_kind(ErrorKind::NotOneOf {
+                        field: name.to_string(),
+                        allowed: values.clone(),
+                    }));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Add the field's label to an error's default message
+    fn labelled(&self, error: ValidationError) -> ValidationError {
+        match &self.label {
+            Some(label) => error.with_label(label),
+            None => error,
+        }
+    }
+}
+
+/// A form's fields, defined at runtime, e.g. deserialized from JSON with the
+/// `serde` feature
+///
+/// # Example
+/// ```rust,ignore
+/// let schema = FormSchema::new()
+///     .with_field(
+///         SchemaField::new("email", FieldType::Text)
+///             .with_label("Email address")
+///             .with_validator(SchemaValidator::Required)
+///             .with_validator(SchemaValidator::Email),
+///     )
+///     .with_field(SchemaField::new("age", FieldType::Integer).with_validator(
+///         SchemaValidator::Range { min: Some(18.0), max: None },
+///     ));
+///
+/// let form = Form::from_schema(schema);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormSchema {
+    /// The fields, in display order
+    pub fields: Vec<SchemaField>,
+}
+
+impl FormSchema {
+    /// Create an empty schema
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field
+    pub fn with_field(mut self, field: SchemaField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Get a field by name
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Check that every `Pattern` validator is a valid regular expression
+    ///
+    /// A field with an invalid pattern always fails validation with the
+    /// `invalid_pattern` code, so check schemas loaded at runtime before
+    /// building a form from them.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let schema: FormSchema = serde_json::from_str(&json)?;
+    /// schema.check_patterns()?;
+    /// let form = Form::from_schema(schema);
+    /// ```
+    pub fn check_patterns(&self) -> Result<(), regex::Error> {
+        self.patterns()
+            .into_values()
+            .try_for_each(|regex| regex.map(drop))
+    }
+
+    /// Compile the regular expressions of the schema's `Pattern` validators
+    fn patterns(&self) -> Patterns {
+        self.fields
+            .iter()
+            .flat_map(|field| &field.validators)
+            .filter_map(|validator| match validator {
+                SchemaValidator::Pattern { pattern } => {
+                    Some((pattern.clone(), regex::Regex::new(pattern)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Compiled regular expressions of a schema's `Pattern` validators, by pattern
+type Patterns = HashMap<String, Result<regex::Regex, regex::Error>>;
+
+/// The typed value of a schema field
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum DynamicValue {
+    /// Value of a `FieldType::Text` field
+    Text(String),
+    /// Value of a `FieldType::Integer` field
+    Integer(i64),
+    /// Value of a `FieldType::Float` field
+    Float(f64),
+    /// Value of a `FieldType::Boolean` field
+    Boolean(bool),
+    /// Value of a number field left blank, which fails `Required`
+    Empty,
+}
+
+impl DynamicValue {
+    /// The value of an untouched field of the given type
+    fn default_for(field_type: FieldType) -> Self {
+        match field_type {
+            FieldType::Text => DynamicValue::Text(String::new()),
+            FieldType::Integer | FieldType::Float => DynamicValue::Empty,
+            FieldType::Boolean => DynamicValue::Boolean(false),
+        }
+    }
+
+    /// Parse a field's string value into the given type
+    fn parse(
+        field_type: FieldType,
+        field_name: &str,
+        value: &str,
+    ) -> Result<Self, ValidationError> {
+        Ok(match field_type {
+            FieldType::Text => DynamicValue::Text(value.to_string()),
+            FieldType::Integer | FieldType::Float if value.trim().is_empty() => DynamicValue::Empty,
+            FieldType::Integer => DynamicValue::Integer(i64::from_field_value(field_name, value)?),
+            FieldType::Float => DynamicValue::Float(f64::from_field_value(field_name, value)?),
+            FieldType::Boolean => DynamicValue::Boolean(bool::from_field_value(field_name, value)?),
+        })
+    }
+
+    /// Get the value as text, if it is text
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            DynamicValue::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the value as an integer, if it is one
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            DynamicValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a decimal, if it is a number
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            DynamicValue::Integer(value) => Some(*value as f64),
+            DynamicValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a boolean, if it is one
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            DynamicValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Convert the value to the string shown in its field
+    pub fn to_field_value(&self) -> String {
+        match self {
+            DynamicValue::Text(value) => value.clone(),
+            DynamicValue::Integer(value) => value.to_field_value(),
+            DynamicValue::Float(value) => value.to_field_value(),
+            DynamicValue::Boolean(value) => value.to_field_value(),
+            DynamicValue::Empty => String::new(),
+        }
+    }
+}
+
+/// Form data described by a `FormSchema` instead of a struct
+///
+/// It implements `FormValidation`, so `Form<DynamicData>` works with `Field`,
+/// `Input` and the rest of the component layer, using the schema's field
+/// names as runtime `name`s. Since the names are only known at runtime,
+/// `FormValidation::field_names`, `field_label` and `is_field_required` fall
+/// back to their defaults; `FormValidation::data_field_names` lists the
+/// schema's fields, and `DynamicData::schema` has the rest.
+///
+/// Text fields start empty, checkboxes unchecked, and number fields blank
+/// rather than at 0, so a `Required` number field fails until it is filled
+/// in.
+///
+/// Create forms with `Form::from_schema`. The `Default` data has an empty
+/// schema, so `Form::<DynamicData>::new()` and `Form::from_json` make a form
+/// without fields; restore a saved draft with `Form::load_json` on a form
+/// created from the schema instead. Resetting the form keeps its schema.
+///
+/// # Example
+/// ```rust,ignore
+/// let form = Form::from_schema(schema.clone());
+///
+/// view! {
+///     <FormComponent form=form let:_form_state>
+///         {schema.fields.iter().map(|field| view! {
+///             <Field<DynamicData, _, _> name=field.name.clone() let:field_state>
+///                 <Input state=field_state />
+///             </Field<DynamicData, _, _>>
+///         }).collect_view()}
+///     </FormComponent>
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DynamicData {
+    schema: Arc<FormSchema>,
+    /// The schema's patterns, compiled once instead of on every validation
+    patterns: Arc<Patterns>,
+    values: HashMap<String, DynamicValue>,
+}
+
+// The patterns are compiled from the schema, so they needn't be compared
+impl PartialEq for DynamicData {
+    fn eq(&self, other: &Self) -> bool {
+        self.schema == other.schema && self.values == other.values
+    }
+}
+
+impl DynamicData {
+    /// Create data for a schema, with every field at its type's default
+    pub fn new(schema: FormSchema) -> Self {
+        let patterns = Arc::new(schema.patterns());
+        Self::with_defaults(Arc::new(schema), patterns)
+    }
+
+    /// Create data for a shared schema, with every field at its type's default
+    fn with_defaults(schema: Arc<FormSchema>, patterns: Arc<Patterns>) -> Self {
+        let values = schema
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.name.clone(),
+                    DynamicValue::default_for(field.field_type),
+                )
+            })
+            .collect();
+        Self {
+            schema,
+            patterns,
+            values,
+        }
+    }
+
+    /// Get the schema
+    pub fn schema(&self) -> &FormSchema {
+        &self.schema
+    }
+
+    /// Get a field's value
+    pub fn get(&self, name: &str) -> Option<&DynamicValue> {
+        self.values.get(name)
+    }
+
+    /// Set a field's value, e.g. to prefill the form before `Form::from`
+    pub fn set(&mut self, name: impl Into<String>, value: DynamicValue) {
+        self.values.insert(name.into(), value);
+    }
+
+    /// Get every field's value
+    pub fn values(&self) -> &HashMap<String, DynamicValue> {
+        &self.values
+    }
+
+    /// Get a field's value, or its type's default if it has none
+    fn value(&self, field: &SchemaField) -> DynamicValue {
+        self.values
+            .get(&field.name)
+            .cloned()
+            .unwrap_or_else(|| DynamicValue::default_for(field.field_type))
+    }
+}
+
+impl FormValidation for DynamicData {
+    fn validate_all(&self) -> HashMap<String, ValidationError> {
+        self.schema
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let error = self.validate_field(&field.name).err()?;
+                Some((field.name.clone(), error))
+            })
+            .collect()
+    }
+
+    fn validate_field(&self, field_name: &str) -> ValidationResult {
+        if let Some(field) = self.schema.field(field_name) {
+            let value = self.value(field);
+            field
+                .validators
+                .iter()
+                .try_for_each(|validator| field.check(validator, &value, &self.patterns))
+                .map_err(|error| field.labelled(error))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_field_all(&self, field_name: &str) -> Vec<ValidationError> {
+        if let Some(field) = self.schema.field(field_name) {
+            let value = self.value(field);
+            field
+                .validators
+                .iter()
+                .filter_map(|validator| field.check(validator, &value, &self.patterns).err())
+                .map(|error| field.labelled(error))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn field_names() -> Vec<&'static str> {
+        // The names live in the schema, only known at runtime
+        Vec::new()
+    }
+
+    fn data_field_names(&self) -> Vec<String> {
+        self.schema
+            .fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect()
+    }
+
+    fn sync_from_strings(
+        &mut self,
+        fields: &HashMap<String, FieldSignal>,
+    ) -> HashMap<String, ValidationError> {
+        use leptos::prelude::*;
+
+        let mut errors = HashMap::new();
+        for field in &self.schema.fields {
+            if let Some(signal) = fields.get(&field.name) {
+                let value = signal.value.get_untracked();
+                match DynamicValue::parse(field.field_type, &field.name, &value) {
+                    Ok(parsed) => {
+                        self.values.insert(field.name.clone(), parsed);
+                    }
+                    Err(error) => {
+                        errors.insert(field.name.clone(), field.labelled(error));
+                    }
+                }
+            }
+        }
+        errors
+    }
+
+    fn to_strings(&self) -> HashMap<String, String> {
+        self.schema
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), self.value(field).to_field_value()))
+            .collect()
+    }
+
+    fn cleared(&self) -> Self {
+        // Keep the schema, which `Default` can't know
+        Self::with_defaults(self.schema.clone(), self.patterns.clone())
+    }
+}
+
+impl Form<DynamicData> {
+    /// Create a form from a runtime schema, with every field at its type's
+    /// default
+    pub fn from_schema(schema: FormSchema) -> Self {
+        Self::from(DynamicData::new(schema))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> FormSchema {
+        FormSchema::new()
+            .with_field(SchemaField::new("name", FieldType::Text))
+            .with_field(
+                SchemaField::new("age", FieldType::Integer)
+                    .with_validator(SchemaValidator::Required),
+            )
+            .with_field(SchemaField::new("weight", FieldType::Float))
+    }
+
+    #[test]
+    fn number_fields_start_blank_so_required_fails() {
+        let data = DynamicData::new(schema());
+        assert_eq!(data.get("age"), Some(&DynamicValue::Empty));
+        assert_eq!(
+            data.to_strings().get("weight").map(String::as_str),
+            Some("")
+        );
+        assert_eq!(data.validate_field("age").unwrap_err().code(), "required");
+
+        let mut data = data;
+        data.set("age", DynamicValue::Integer(0));
+        assert!(data.validate_field("age").is_ok());
+    }
+
+    #[test]
+    fn blank_number_text_parses_as_empty() {
+        assert_eq!(
+            DynamicValue::parse(FieldType::Float, "weight", " "),
+            Ok(DynamicValue::Empty)
+        );
+        assert_eq!(
+            DynamicValue::parse(FieldType::Integer, "age", "42"),
+            Ok(DynamicValue::Integer(42))
+        );
+    }
+
+    #[test]
+    fn data_field_names_come_from_the_schema() {
+        let data = DynamicData::new(schema());
+        assert!(DynamicData::field_names().is_empty());
+        assert_eq!(data.data_field_names(), ["name", "age", "weight"]);
+    }
+}
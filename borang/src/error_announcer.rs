@@ -0,0 +1,50 @@
+use leptos::prelude::*;
+
+use super::form::{use_form, Form};
+use super::validation::FormValidation;
+
+/// Hides the live region visually while keeping it readable by screen readers
+const VISUALLY_HIDDEN: &str = "position:absolute;width:1px;height:1px;padding:0;margin:-1px;overflow:hidden;clip:rect(0,0,0,0);white-space:nowrap;border:0";
+
+/// ErrorAnnouncer component that reads out failed submits to screen readers
+///
+/// This renders an `aria-live` region with the form's `announcement`, such as
+/// "3 fields need attention", set when a `handle_submit` submit fails
+/// validation. The region is visually hidden unless a `class` is given.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <form on:submit=form.handle_submit(save_contact)>
+///         <ErrorAnnouncer form=form />
+///         // ...
+///     </form>
+/// }
+/// ```
+#[component]
+pub fn ErrorAnnouncer<T>(
+    /// The form whose failed submits are announced (defaults to the form of
+    /// the enclosing `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
+    /// Class for the live region, which shows it instead of hiding it visually
+    #[prop(into, optional)]
+    class: &'static str,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let form = form.unwrap_or_else(use_form::<T>);
+    let style = if class.is_empty() {
+        VISUALLY_HIDDEN
+    } else {
+        ""
+    };
+    let announcement = form.announcement();
+
+    view! {
+        <div role="status" aria-live="polite" aria-atomic="true" class=class style=style>
+            {move || announcement.get()}
+        </div>
+    }
+}
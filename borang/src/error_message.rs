@@ -0,0 +1,67 @@
+use leptos::prelude::*;
+
+use crate::{validation::FormValidation, FieldState, ValidationError};
+
+/// ErrorMessage component for form fields.
+///
+/// This component renders the field's visible error, following the form's
+/// `ShowErrors` policy, and nothing while there is none. The element's id is
+/// the field's `error_id`, which `Input` and `Select` point `aria-describedby`
+/// at.
+///
+/// Set `only_touched` to hold the error back until the field is touched or
+/// the form is submitted, and `render` to render the error yourself.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="email" let:field_state>
+///         <Input state=field_state.clone() />
+///         <ErrorMessage state=field_state class="error" only_touched=true />
+///     </Field>
+///     <Field form=form name="age" let:field_state>
+///         <Input state=field_state.clone() />
+///         <ErrorMessage
+///             state=field_state
+///             render=|error: ValidationError| view! { <b>{error.message().to_string()}</b> }.into_any()
+///         />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn ErrorMessage<T>(
+    state: FieldState<T>,
+    #[prop(into, optional)] class: &'static str,
+    /// Only show the error once the field is touched or the form submitted
+    #[prop(optional)]
+    only_touched: bool,
+    /// Renders the error in place of its message
+    #[prop(optional, into)]
+    render: Option<Callback<ValidationError, AnyView>>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let error = Signal::derive(move || {
+        if only_touched && !state.touched.get() && !state.form.state_signal().with(|s| s.submitted)
+        {
+            return None;
+        }
+        state.visible_error.get()
+    });
+
+    view! {
+        <Show when=move || error.get().is_some()>
+            <span id=state.error_id() class=class>
+                {move || {
+                    error
+                        .get()
+                        .map(|error| match render {
+                            Some(render) => render.run(error),
+                            None => error.message().to_string().into_any(),
+                        })
+                }}
+            </span>
+        </Show>
+    }
+}
@@ -0,0 +1,90 @@
+use leptos::prelude::*;
+
+use super::form::{use_form, Form};
+use super::validation::{FormValidation, ValidationError, FORM_ERROR_KEY};
+
+/// ErrorSummary component that lists a submitted form's errors
+///
+/// Once the form has been submitted, this renders every current error, the
+/// same errors as `FormComponentState::errors` without the warnings, with the
+/// field's label and the message. Each entry links to the element whose id is
+/// the field name, as with `Label`. Errors are listed in the order the fields
+/// are declared in the form struct, and nothing renders while there are none.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <FormComponent form=form let:_form_state>
+///         <ErrorSummary<ContactForm> title="There is a problem" class="error-summary" />
+///         // ...
+///     </FormComponent>
+/// }
+/// ```
+#[component]
+pub fn ErrorSummary<T>(
+    /// The form whose errors are listed (defaults to the form of the
+    /// enclosing `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
+    /// Heading shown above the list
+    #[prop(into, optional)]
+    title: Option<&'static str>,
+    #[prop(into, optional)] class: &'static str,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let form = form.unwrap_or_else(use_form::<T>);
+    let title = title.unwrap_or("Please fix the following errors");
+
+    let errors = Signal::derive(move || {
+        let state = form.state_signal().get();
+        if !state.submitted {
+            return Vec::new();
+        }
+        let field_names = form.data().data_field_names();
+        let mut errors: Vec<(String, ValidationError)> = state
+            .errors
+            .into_iter()
+            .filter(|(_, error)| !error.is_warning())
+            .collect();
+        errors.sort_by_cached_key(|(name, _)| {
+            let position = field_names.iter().position(|field_name| field_name == name);
+            (position.unwrap_or(usize::MAX), name.clone())
+        });
+        errors
+    });
+
+    view! {
+        <Show when=move || !errors.get().is_empty()>
+            <div role="alert" class=class>
+                <p>{title}</p>
+                <ul>
+                    <For
+                        each=move || errors.get()
+                        key=|(name, error)| (name.clone(), error.message().to_string())
+                        let:entry
+                    >
+                        {
+                            let (name, error) = entry;
+                            let message = error.message().to_string();
+                            if name == FORM_ERROR_KEY {
+                                view! { <li>{message}</li> }.into_any()
+                            } else {
+                                let label = T::field_label(&name)
+                                    .map(str::to_string)
+                                    .unwrap_or_else(|| name.clone());
+                                view! {
+                                    <li>
+                                        <a href=format!("#{}", name)>{label}": "{message}</a>
+                                    </li>
+                                }
+                                    .into_any()
+                            }
+                        }
+                    </For>
+                </ul>
+            </div>
+        </Show>
+    }
+}
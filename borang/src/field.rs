@@ -1,33 +1,58 @@
 use leptos::prelude::*;
 
-use super::form::Form;
-use super::validation::{FormValidation, ValidationError};
+use super::form::{use_form, BindOn, Form, ShowErrors, ValidateMode};
+use super::validation::{FieldFormat, FormValidation, ValidationError};
 
 /// State object provided by Field component containing error, dirty, touched signals and form reference
 #[derive(Clone)]
 pub struct FieldState<T: FormValidation> {
     /// Current validation error for this field
     pub err: Signal<Option<ValidationError>>,
+    /// Every current validation error for this field (requires `ErrorMode::All`)
+    pub all_errors: Signal<Vec<ValidationError>>,
+    /// Current validation error, filtered by the form's `ShowErrors` policy
+    pub visible_error: Signal<Option<ValidationError>>,
     /// True if field value differs from initial value
     pub dirty: Signal<bool>,
     /// True if field has been marked as touched
     pub touched: Signal<bool>,
+    /// True while the field's async validators are running
+    pub pending: Signal<bool>,
     /// The name of this field
-    pub field_name: &'static str,
+    pub field_name: Oco<'static, str>,
+    /// Which DOM event updates this field's value
+    pub bind_on: BindOn,
+    /// When this field is validated, overriding the form's validate modes
+    pub validate_on: Option<ValidateMode>,
+    /// How the field's value is shown in its input
+    pub format: Option<FieldFormat>,
     /// Reference to the parent form
     pub form: Form<T>,
 }
 
-// Manually implement Copy for FieldState<T> regardless of whether T is Copy
-// This is safe because FieldState only contains Copy types (Signal, &'static str, and Form<T> which is Copy)
-impl<T: FormValidation + Clone> Copy for FieldState<T> {}
-
 impl<T: FormValidation + Default + Clone + Send + Sync + 'static> FieldState<T> {
     /// Mark this field as touched (typically called on blur)
+    ///
+    /// This also validates the field, including its async validators, when its
+    /// active validate mode is `ValidateMode::OnBlur`.
     pub fn mark_touched(&self) {
         self.form.state_signal().update(|s| {
             s.touched.insert(self.field_name.to_string(), true);
         });
+        if self.active_validate_mode() == ValidateMode::OnBlur {
+            let form = self.form;
+            let field_name = self.field_name.clone();
+            leptos::task::spawn_local(async move {
+                form.validate_field_async(&field_name).await;
+            });
+        }
+    }
+
+    /// Get the validate mode in effect for this field: its `validate_on`
+    /// override, or else the form's active validate mode
+    pub fn active_validate_mode(&self) -> ValidateMode {
+        self.validate_on
+            .unwrap_or_else(|| self.form.active_validate_mode())
     }
 
     pub fn has_error(&self) -> bool {
@@ -38,12 +63,56 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> FieldState<T>
         self.err.get()
     }
 
+    /// True if the field has an error the display policy allows showing
+    pub fn has_visible_error(&self) -> bool {
+        self.visible_error.get().is_some()
+    }
+
+    /// Get the error the display policy allows showing
+    pub fn get_visible_error(&self) -> Option<ValidationError> {
+        self.visible_error.get()
+    }
+
+    /// Get the field's label, falling back to its name
+    pub fn label(&self) -> &str {
+        T::field_label(&self.field_name).unwrap_or(&self.field_name)
+    }
+
+    /// True if the field has a `required` validator
+    pub fn is_required(&self) -> bool {
+        T::is_field_required(&self.field_name)
+    }
+
+    /// Get the id of the element showing this field's error, which the
+    /// bundled inputs reference with `aria-describedby`
+    pub fn error_id(&self) -> String {
+        format!("{}-error", self.field_name)
+    }
+
+    /// Get the text an input shows for a value of this field, formatted by
+    /// its `format`
+    pub fn format_value(&self, value: &str) -> String {
+        match self.format {
+            Some(format) => format.format(value),
+            None => value.to_string(),
+        }
+    }
+
+    /// Get the value to store for text typed into this field's input, parsed
+    /// by its `format`
+    pub fn parse_input(&self, text: &str) -> String {
+        match self.format {
+            Some(format) => format.parse(text),
+            None => text.to_string(),
+        }
+    }
+
     /// Get the RwSignal for this field's value
     pub fn value(&self) -> RwSignal<String> {
         self.form.state_signal().with_untracked(|state| {
             state
                 .fields
-                .get(self.field_name)
+                .get(self.field_name.as_str())
                 .map(|field| field.value)
                 .unwrap_or_else(|| RwSignal::new(String::new()))
         })
@@ -52,6 +121,13 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> FieldState<T>
 
 /// Field component that binds to a specific field in the parent form
 ///
+/// The `name` may be a `&'static str` or a `String` built at runtime, e.g. when
+/// rendering fields from a config-driven schema.
+///
+/// The `form` prop can be left out inside a `FormComponent`, which provides
+/// the form through context. The form type is then named explicitly, as in
+/// `<Field<MyForm, _, _> name="email" let:field_state>`.
+///
 /// This component:
 /// - Registers the field with the parent form via context
 /// - Creates reactive value and error signals from form state
@@ -78,10 +154,23 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> FieldState<T>
 /// ```
 #[component]
 pub fn Field<T, F, IV>(
-    /// Form instance to register the field with
-    form: Form<T>,
+    /// Form instance to register the field with (defaults to the form of the
+    /// enclosing `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
     /// The name of the field (must match a field in the form struct)
-    name: &'static str,
+    #[prop(into)]
+    name: Oco<'static, str>,
+    /// Which DOM event updates the field value (defaults to the form-wide setting)
+    #[prop(optional, into)]
+    bind_on: Option<BindOn>,
+    /// When the field is validated (defaults to the form's validate modes)
+    #[prop(optional, into)]
+    validate_on: Option<ValidateMode>,
+    /// How the field's value is shown in its input (defaults to the field's
+    /// `#[field(mask = "...")]`)
+    #[prop(optional, into)]
+    format: Option<FieldFormat>,
     /// Children function that receives (value, set_value, state)
     children: F,
 ) -> impl IntoView
@@ -90,10 +179,11 @@ where
     F: Fn(FieldState<T>) -> IV + 'static,
     IV: IntoView,
 {
+    let form = form.unwrap_or_else(use_form::<T>);
     let state = form.state_signal();
 
     // Register field with form state (get or create the field signal)
-    let field_signal = state.update_untracked(|s| s.get_or_create_field(name));
+    let field_signal = state.update_untracked(|s| s.get_or_create_field(&name));
 
     // Set up an effect to handle reactive validation when value changes
     // This enables immediate validation feedback as users type
@@ -101,14 +191,24 @@ where
         let name = name.to_string();
         let field_signal = field_signal.clone();
 
-        Effect::new(move || {
+        Effect::new(move |prev: Option<()>| {
             // Track the value - this effect runs whenever the value changes
             let _value = field_signal.value.get();
+            form.bump_generation();
+
+            // The first run happens on mount, before any user interaction
+            if prev.is_none() && !form.validate_on_mount() {
+                return;
+            }
 
-            // Trigger field-level validation
+            // Trigger field-level validation, unless the validate mode defers it
+            // to blur or submit
             // This updates the error state reactively, which causes the error signal
             // to update automatically, providing immediate feedback to the user
-            form.validate_field(&name);
+            let mode = validate_on.unwrap_or_else(|| form.active_validate_mode());
+            if mode == ValidateMode::OnChange {
+                form.validate_field(&name);
+            }
         });
     }
 
@@ -118,6 +218,38 @@ where
         move || state.get().errors.get(&name).cloned()
     });
 
+    // Create reactive signal listing every error for this field
+    let all_errors = Signal::derive({
+        let name = name.to_string();
+        move || {
+            state
+                .get()
+                .all_errors
+                .get(&name)
+                .cloned()
+                .unwrap_or_default()
+        }
+    });
+
+    // Create reactive visible error signal, filtered by the form's display policy
+    let visible_error = Signal::derive({
+        let name = name.to_string();
+        let show_errors = form.show_errors();
+        move || {
+            let state = state.get();
+            let visible = match show_errors {
+                ShowErrors::Always => true,
+                ShowErrors::OnTouched => state.submitted || state.is_field_touched(&name),
+                ShowErrors::OnSubmit => state.submitted,
+            };
+            if visible {
+                state.errors.get(&name).cloned()
+            } else {
+                None
+            }
+        }
+    });
+
     // Create reactive dirty signal for this field
     let dirty = Signal::derive({
         let name = name.to_string();
@@ -130,11 +262,23 @@ where
         move || state.get().is_field_touched(&name)
     });
 
+    // Create reactive pending signal for this field's async validation
+    let pending = Signal::derive({
+        let name = name.to_string();
+        move || state.get().is_field_pending(&name)
+    });
+
     // Create FieldState object
     let field_state = FieldState {
         err: error,
+        all_errors,
+        visible_error,
         dirty,
         touched,
+        pending,
+        bind_on: bind_on.unwrap_or_else(|| form.bind_on()),
+        validate_on,
+        format: format.or_else(|| T::field_format(&name)),
         field_name: name,
         form,
     };
@@ -159,10 +303,13 @@ where
 /// ```
 #[component]
 pub fn GetField<T, F, IV>(
-    /// The form instance to which this field belongs
-    form: Form<T>,
+    /// The form instance to which this field belongs (defaults to the form of
+    /// the enclosing `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
     /// The name of the field (must match a field in the form struct)
-    name: &'static str,
+    #[prop(into)]
+    name: Oco<'static, str>,
     /// Children function that receives (value)
     children: F,
 ) -> impl IntoView
@@ -171,10 +318,10 @@ where
     F: Fn(Signal<String>) -> IV + 'static,
     IV: IntoView,
 {
-    let state = form.state_signal();
+    let state = form.unwrap_or_else(use_form::<T>).state_signal();
 
     // Register field with form state (get or create the field signal)
-    let field_signal = state.update_untracked(|s| s.get_or_create_field(name));
+    let field_signal = state.update_untracked(|s| s.get_or_create_field(&name));
 
     // Create reactive value signal for this field
     let value = Signal::derive({
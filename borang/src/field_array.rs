@@ -0,0 +1,511 @@
+use leptos::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use super::form::{use_form, Form, FormState};
+use super::nested::sub_fields;
+use super::validation::{
+    FieldFormat, FieldSignal, FormValidation, NumberBounds, TextBounds, ValidationError,
+    ValidationResult,
+};
+
+/// Handle to a repeated group of fields backed by a `Vec` field, obtained with
+/// `Form::field_array`
+///
+/// The struct field is marked `#[field(array)]` and holds items that derive
+/// `FormValidation` themselves. Item fields are addressed with indexed names
+/// such as `phones[0].number`, and the item count is kept under `phones[]`.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FormValidation, Default, Clone)]
+/// struct Phone {
+///     #[validator(required)]
+///     number: String,
+/// }
+///
+/// #[derive(FormValidation, Default, Clone)]
+/// struct Contact {
+///     #[field(array)]
+///     phones: Vec<Phone>,
+/// }
+///
+/// let phones = form.field_array(Contact::PHONES);
+/// phones.push(&Phone::default());
+/// phones.swap(0, 1);
+/// ```
+#[derive(Clone)]
+pub struct FieldArrayState<T: FormValidation> {
+    form: Form<T>,
+    name: Oco<'static, str>,
+}
+
+impl<T: FormValidation + Default + Clone + Send + Sync + 'static> FieldArrayState<T> {
+    pub(crate) fn new(form: Form<T>, name: Oco<'static, str>) -> Self {
+        Self { form, name }
+    }
+
+    /// The name of the array field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the number of items, tracking changes when read reactively
+    pub fn len(&self) -> usize {
+        let len_key = len_key(&self.name);
+        self.form.state_signal().with(|state| {
+            state
+                .fields
+                .get(&len_key)
+                .and_then(|field| field.value.get().parse().ok())
+                .unwrap_or(0)
+        })
+    }
+
+    /// True if the array has no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the indexed name of an item's field, e.g. `phones[0].number`, for
+    /// use as the `name` of a `Field`
+    pub fn field_name(&self, index: usize, field_name: &str) -> String {
+        format!("{}{}", item_prefix(&self.name, index), field_name)
+    }
+
+    /// Get the item at `index`
+    pub fn item(&self, index: usize) -> FieldArrayItem<T> {
+        FieldArrayItem {
+            array: self.clone(),
+            index,
+        }
+    }
+
+    /// Append an item, creating its fields from the item's values
+    pub fn push<I: FormValidation>(&self, item: &I) {
+        let values = item.to_strings();
+        self.edit_items(|items| {
+            items.push(ItemState {
+                values,
+                ..ItemState::default()
+            })
+        });
+    }
+
+    /// Remove the item at `index`, shifting later items down
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&self, index: usize) {
+        self.edit_items(|items| {
+            items.remove(index);
+        });
+    }
+
+    /// Swap the items at `a` and `b`
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&self, a: usize, b: usize) {
+        self.edit_items(|items| items.swap(a, b));
+    }
+
+    /// Move the item at `from` to `to`, shifting the items in between
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_item(&self, from: usize, to: usize) {
+        self.edit_items(|items| {
+            let item = items.remove(from);
+            items.insert(to, item);
+        });
+    }
+
+    /// Rearrange the items' values, errors and touched state
+    ///
+    /// The field signals stay in place, so mounted `Field`s keep working and
+    /// only see their values change. Fields of items past the new end are
+    /// removed, and the typed data is synced to the new items.
+    fn edit_items(&self, edit: impl FnOnce(&mut Vec<ItemState>)) {
+        let name = &self.name;
+        self.form.state_signal().update(|state| {
+            let len = state
+                .fields
+                .get(&len_key(name))
+                .and_then(|field| field.value.get_untracked().parse().ok())
+                .unwrap_or(0);
+            let mut items: Vec<_> = (0..len)
+                .map(|index| ItemState::take(state, &item_prefix(name, index)))
+                .collect();
+
+            edit(&mut items);
+
+            for (index, item) in items.iter().enumerate() {
+                item.restore(state, &item_prefix(name, index));
+            }
+            for index in items.len()..len {
+                let prefix = item_prefix(name, index);
+                state.fields.retain(|key, _| !key.starts_with(&prefix));
+                state.pending.retain(|key| !key.starts_with(&prefix));
+            }
+            state
+                .get_or_create_field(&len_key(name))
+                .value
+                .set(items.len().to_string());
+        });
+        self.form.sync_to_form_data();
+        self.form.bump_generation();
+    }
+}
+
+/// A single item of a field array, passed to the children of `FieldArray`
+#[derive(Clone)]
+pub struct FieldArrayItem<T: FormValidation> {
+    array: FieldArrayState<T>,
+    index: usize,
+}
+
+impl<T: FormValidation + Default + Clone + Send + Sync + 'static> FieldArrayItem<T> {
+    /// The position of this item in the array
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the indexed name of this item's field, e.g. `phones[0].number`
+    pub fn field_name(&self, field_name: &str) -> String {
+        self.array.field_name(self.index, field_name)
+    }
+
+    /// Get the array this item belongs to
+    pub fn array(&self) -> FieldArrayState<T> {
+        self.array.clone()
+    }
+
+    /// Remove this item from the array
+    pub fn remove(&self) {
+        self.array.remove(self.index);
+    }
+}
+
+/// The values, errors and touched state of one item, keyed by item field name
+#[derive(Default)]
+struct ItemState {
+    values: HashMap<String, String>,
+    errors: HashMap<String, ValidationError>,
+    all_errors: HashMap<String, Vec<ValidationError>>,
+    touched: HashSet<String>,
+}
+
+impl ItemState {
+    /// Take the state of the item whose fields start with `prefix`
+    fn take(state: &mut FormState, prefix: &str) -> Self {
+        let strip = |key: &String| key.strip_prefix(prefix).map(str::to_string);
+        Self {
+            values: state
+                .fields
+                .iter()
+                .filter_map(|(key, field)| Some((strip(key)?, field.value.get_untracked())))
+                .collect(),
+            errors: take_prefixed(&mut state.errors, prefix),
+            all_errors: take_prefixed(&mut state.all_errors, prefix),
+            touched: take_prefixed(&mut state.touched, prefix)
+                .into_iter()
+                .filter_map(|(key, touched)| touched.then_some(key))
+                .collect(),
+        }
+    }
+
+    /// Write this item's state to the fields starting with `prefix`
+    fn restore(&self, state: &mut FormState, prefix: &str) {
+        // Fields this item has no value for are cleared
+        for (key, field) in state.fields.iter() {
+            if let Some(field_name) = key.strip_prefix(prefix) {
+                if !self.values.contains_key(field_name) {
+                    field.value.set(String::new());
+                }
+            }
+        }
+        for (field_name, value) in &self.values {
+            let key = format!("{}{}", prefix, field_name);
+            state.get_or_create_field(&key).value.set(value.clone());
+        }
+        for (field_name, error) in &self.errors {
+            state
+                .errors
+                .insert(format!("{}{}", prefix, field_name), error.clone());
+        }
+        for (field_name, errors) in &self.all_errors {
+            state
+                .all_errors
+                .insert(format!("{}{}", prefix, field_name), errors.clone());
+        }
+        for field_name in &self.touched {
+            state
+                .touched
+                .insert(format!("{}{}", prefix, field_name), true);
+        }
+    }
+}
+
+/// Remove the entries whose key starts with `prefix`, keyed without it
+fn take_prefixed<V>(map: &mut HashMap<String, V>, prefix: &str) -> HashMap<String, V> {
+    let keys: Vec<_> = map
+        .keys()
+        .filter(|key| key.starts_with(prefix))
+        .cloned()
+        .collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let value = map.remove(&key)?;
+            Some((key[prefix.len()..].to_string(), value))
+        })
+        .collect()
+}
+
+/// The key holding an array's item count, e.g. `phones[]`
+fn len_key(array: &str) -> String {
+    format!("{}[]", array)
+}
+
+/// The prefix of an item's field names, e.g. `phones[0].`
+fn item_prefix(array: &str, index: usize) -> String {
+    format!("{}[{}].", array, index)
+}
+
+/// Split an indexed field name like `phones[0].number` into `(0, "number")`
+fn split_item_name<'a>(array: &str, name: &'a str) -> Option<(usize, &'a str)> {
+    let rest = name.strip_prefix(array)?.strip_prefix('[')?;
+    let (index, field_name) = rest.split_once("].")?;
+    Some((index.parse().ok()?, field_name))
+}
+
+// Helpers called by the code `#[derive(FormValidation)]` generates for
+// `#[field(array)]` fields
+
+#[doc(hidden)]
+pub fn sync_items<I: FormValidation + Default>(
+    items: &mut Vec<I>,
+    array: &str,
+    fields: &HashMap<String, FieldSignal>,
+) -> HashMap<String, ValidationError> {
+    // The item count is only known when syncing every field, not a single one
+    if let Some(field) = fields.get(&len_key(array)) {
+        let len = field.value.get_untracked().parse().unwrap_or(0);
+        items.resize_with(len, I::default);
+    }
+
+    let mut errors = HashMap::new();
+    for (index, item) in items.iter_mut().enumerate() {
+        let prefix = item_prefix(array, index);
+        let item_fields = sub_fields(fields, &prefix);
+        if item_fields.is_empty() {
+            continue;
+        }
+        for (field_name, error) in item.sync_from_strings(&item_fields) {
+            errors.insert(format!("{}{}", prefix, field_name), error);
+        }
+    }
+    errors
+}
+
+#[doc(hidden)]
+pub fn items_to_strings<I: FormValidation>(
+    items: &[I],
+    array: &str,
+    map: &mut HashMap<String, String>,
+) {
+    map.insert(len_key(array), items.len().to_string());
+    for (index, item) in items.iter().enumerate() {
+        let prefix = item_prefix(array, index);
+        for (field_name, value) in item.to_strings() {
+            map.insert(format!("{}{}", prefix, field_name), value);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn validate_items<I: FormValidation>(
+    items: &[I],
+    array: &str,
+    errors: &mut HashMap<String, ValidationError>,
+) {
+    for (index, item) in items.iter().enumerate() {
+        let prefix = item_prefix(array, index);
+        for (field_name, error) in item.validate_all() {
+            errors.insert(format!("{}{}", prefix, field_name), error);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn validate_item<I: FormValidation>(items: &[I], array: &str, name: &str) -> ValidationResult {
+    match split_item_name(array, name) {
+        Some((index, field_name)) => items
+            .get(index)
+            .map_or(Ok(()), |item| item.validate_field(field_name)),
+        None => Ok(()),
+    }
+}
+
+#[doc(hidden)]
+pub fn validate_item_all<I: FormValidation>(
+    items: &[I],
+    array: &str,
+    name: &str,
+) -> Vec<ValidationError> {
+    match split_item_name(array, name) {
+        Some((index, field_name)) => items
+            .get(index)
+            .map(|item| item.validate_field_all(field_name))
+            .unwrap_or_default(),
+        None => Vec::new(),
+    }
+}
+
+#[doc(hidden)]
+pub fn item_label<I: FormValidation>(array: &str, name: &str) -> Option<&'static str> {
+    let (_, field_name) = split_item_name(array, name)?;
+    I::field_label(field_name)
+}
+
+#[doc(hidden)]
+pub fn item_required<I: FormValidation>(array: &str, name: &str) -> bool {
+    split_item_name(array, name).is_some_and(|(_, field_name)| I::is_field_required(field_name))
+}
+
+#[doc(hidden)]
+pub fn item_number_bounds<I: FormValidation>(array: &str, name: &str) -> NumberBounds {
+    split_item_name(array, name)
+        .map(|(_, field_name)| I::number_bounds(field_name))
+        .unwrap_or_default()
+}
+
+#[doc(hidden)]
+pub fn item_text_bounds<I: FormValidation>(array: &str, name: &str) -> TextBounds {
+    split_item_name(array, name)
+        .map(|(_, field_name)| I::text_bounds(field_name))
+        .unwrap_or_default()
+}
+
+#[doc(hidden)]
+pub fn item_format<I: FormValidation>(array: &str, name: &str) -> Option<FieldFormat> {
+    let (_, field_name) = split_item_name(array, name)?;
+    I::field_format(field_name)
+}
+
+/// FieldArray component that renders its children once per item of an array
+/// field
+///
+/// Each item's fields are bound with `Field`, using the indexed names from
+/// `FieldArrayItem::field_name`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <FieldArray form=form name=Contact::PHONES let:item>
+///         <Field form=form name=item.field_name("number") let:field_state>
+///             <Input state=field_state />
+///         </Field>
+///         <button type="button" on:click=move |_| item.remove()>"Remove"</button>
+///     </FieldArray>
+///     <button type="button" on:click=move |_| form.field_array(Contact::PHONES).push(&Phone::default())>
+///         "Add phone"
+///     </button>
+/// }
+/// ```
+#[component]
+pub fn FieldArray<T, F, IV>(
+    /// Form instance the array field belongs to (defaults to the form of the
+    /// enclosing `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
+    /// The name of the array field (must be a `#[field(array)]` field)
+    #[prop(into)]
+    name: Oco<'static, str>,
+    /// Children function that receives each item
+    children: F,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+    F: Fn(FieldArrayItem<T>) -> IV + Send + Clone + 'static,
+    IV: IntoView + 'static,
+{
+    let array = form.unwrap_or_else(use_form::<T>).field_array(name);
+    let items = array.clone();
+
+    // Items are keyed by index: their field signals stay in place as items are
+    // rearranged, only the values move
+    view! {
+        <For
+            each=move || 0..items.len()
+            key=|index: &usize| *index
+            children=move |index| children(array.item(index))
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Form state with two `phones` items, the second one touched and invalid
+    fn phones_state() -> FormState {
+        let mut state = FormState::new();
+        for (key, value) in [
+            ("phones[0].number", "0123"),
+            ("phones[1].number", "x"),
+            ("phones[1].label", "Work"),
+            ("phones[10].number", "0456"),
+        ] {
+            state.get_or_create_field(key).value.set(value.to_string());
+        }
+        state.errors.insert(
+            "phones[1].number".to_string(),
+            ValidationError::new("phones[1].number", "Invalid"),
+        );
+        state.touched.insert("phones[1].number".to_string(), true);
+        state.touched.insert("phones[0].number".to_string(), false);
+        state
+    }
+
+    fn value(state: &FormState, key: &str) -> String {
+        state.fields[key].value.get_untracked()
+    }
+
+    #[test]
+    fn take_strips_the_item_prefix() {
+        let mut state = phones_state();
+        let item = ItemState::take(&mut state, &item_prefix("phones", 1));
+        assert_eq!(item.values.len(), 2);
+        assert_eq!(item.values["number"], "x");
+        assert_eq!(item.values["label"], "Work");
+        assert!(item.errors.contains_key("number"));
+        assert_eq!(item.touched, HashSet::from(["number".to_string()]));
+        // Errors and touched state move to the item, while fields stay
+        assert!(state.errors.is_empty());
+        assert!(!state.touched.contains_key("phones[1].number"));
+        assert_eq!(value(&state, "phones[1].number"), "x");
+    }
+
+    #[test]
+    fn restore_moves_items_to_new_positions() {
+        let mut state = phones_state();
+        let first = ItemState::take(&mut state, &item_prefix("phones", 0));
+        let second = ItemState::take(&mut state, &item_prefix("phones", 1));
+        second.restore(&mut state, &item_prefix("phones", 0));
+        first.restore(&mut state, &item_prefix("phones", 1));
+
+        assert_eq!(value(&state, "phones[0].number"), "x");
+        assert_eq!(value(&state, "phones[0].label"), "Work");
+        assert_eq!(value(&state, "phones[1].number"), "0123");
+        // The first item had no label, so the one left at its new position is cleared
+        assert_eq!(value(&state, "phones[1].label"), "");
+        assert!(state.errors.contains_key("phones[0].number"));
+        assert!(!state.errors.contains_key("phones[1].number"));
+        assert_eq!(state.touched.get("phones[0].number"), Some(&true));
+        assert_eq!(state.touched.get("phones[1].number"), None);
+        assert_eq!(value(&state, "phones[10].number"), "0456");
+    }
+}
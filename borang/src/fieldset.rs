@@ -0,0 +1,155 @@
+use leptos::prelude::*;
+
+use crate::{
+    form::{use_form, Form},
+    validation::{FormValidation, ValidationError},
+};
+
+/// State object provided by the Fieldset component, summarizing its fields
+#[derive(Clone, Copy)]
+pub struct FieldsetState {
+    /// True if none of the group's fields fails validation with its current
+    /// value (warnings are allowed), whether or not it was validated yet
+    pub valid: Signal<bool>,
+    /// True if any of the group's fields differs from its initial value
+    pub dirty: Signal<bool>,
+    /// True if any of the group's fields has been touched
+    pub touched: Signal<bool>,
+    /// Number of the group's fields with a current validation error
+    pub error_count: Signal<usize>,
+}
+
+/// Fieldset component that groups several fields under a legend
+///
+/// `fields` names the fields in the group. A nested or array field's name
+/// covers all of its fields, e.g. `address` covers `address.street`. Fields
+/// hidden by their `show_when` rule are left out.
+///
+/// The children get a `FieldsetState` with the group's validity and dirty and
+/// touched state, so step indicators and section badges can show how far the
+/// user got. Unlike `FormComponentState::valid`, `valid` runs the group's
+/// validators on the current values, so an untouched required field counts
+/// as invalid.
+///
+/// The `form` prop can be left out inside a `FormComponent`, like on `Field`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Fieldset
+///         form=form
+///         fields=vec![Signup::NAME, Signup::EMAIL]
+///         legend="Contact details"
+///         let:group
+///     >
+///         <span class="badge" class:done=move || group.valid.get()>"✓"</span>
+///         <Field form=form name=Signup::NAME let:field_state>
+///             <Input state=field_state />
+///         </Field>
+///         <Field form=form name=Signup::EMAIL let:field_state>
+///             <Input state=field_state input_type="email" />
+///         </Field>
+///     </Fieldset>
+/// }
+/// ```
+#[component]
+pub fn Fieldset<T, F, IV>(
+    /// Form the fields belong to (defaults to the form of the enclosing
+    /// `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
+    /// Names of the fields in the group
+    #[prop(into)]
+    fields: Vec<&'static str>,
+    /// Text of the `<legend>`, left out when not set
+    #[prop(optional)]
+    legend: Option<&'static str>,
+    #[prop(into, optional)] class: &'static str,
+    #[prop(into, optional)] legend_class: &'static str,
+    /// Children function that receives the group's state
+    children: F,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+    F: Fn(FieldsetState) -> IV + 'static,
+    IV: IntoView,
+{
+    let form = form.unwrap_or_else(use_form::<T>);
+    let fields = StoredValue::new(fields);
+    let in_group = move |name: &str| {
+        fields.with_value(|fields| fields.iter().any(|field| is_in_group(field, name)))
+    };
+    let state = form.state_signal();
+    let data = form.data_signal();
+
+    let valid = Signal::derive(move || {
+        let has_error = |name: &String, error: &ValidationError| {
+            !error.is_warning() && in_group(name) && form.is_field_visible(name)
+        };
+        let fails_now = data.with(|data| {
+            data.validate_all()
+                .iter()
+                .any(|(name, error)| has_error(name, error))
+        });
+        // Parse and server errors only show up in the form's errors
+        let has_form_error = state.with(|state| {
+            state
+                .errors
+                .iter()
+                .any(|(name, error)| has_error(name, error))
+        });
+        !fails_now && !has_form_error
+    });
+
+    let dirty = Signal::derive(move || {
+        state.with(|state| {
+            state.fields.iter().any(|(name, field)| {
+                in_group(name)
+                    && state
+                        .initial_values
+                        .get(name)
+                        .is_some_and(|initial| field.value.get() != *initial)
+            })
+        })
+    });
+
+    let touched = Signal::derive(move || {
+        state.with(|state| {
+            state
+                .touched
+                .iter()
+                .any(|(name, &touched)| touched && in_group(name))
+        })
+    });
+
+    let error_count = Signal::derive(move || {
+        state.with(|state| {
+            state
+                .errors
+                .iter()
+                .filter(|(name, error)| !error.is_warning() && in_group(name))
+                .count()
+        })
+    });
+
+    let fieldset_state = FieldsetState {
+        valid,
+        dirty,
+        touched,
+        error_count,
+    };
+
+    view! {
+        <fieldset class=class>
+            {legend.map(|legend| view! { <legend class=legend_class>{legend}</legend> })}
+            {children(fieldset_state)}
+        </fieldset>
+    }
+}
+
+/// Check whether a field belongs to a group entry, as the field itself or one
+/// of its nested or array fields
+fn is_in_group(entry: &str, name: &str) -> bool {
+    name.strip_prefix(entry)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+}
@@ -0,0 +1,99 @@
+use leptos::prelude::*;
+use web_sys::{File, HtmlInputElement};
+
+use crate::{
+    validation::{FileInfo, FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// FileInput component for file form fields.
+///
+/// This component renders an `<input type="file">` and stores the `FileInfo`
+/// of each selected file as the field's value, so `Option<FileInfo>` and
+/// `Vec<FileInfo>` fields can be checked with the `file` validator. The
+/// selected files themselves are available from `Form::files`. It sets up mark
+/// touched on blur and sets the same ARIA attributes as `Input`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="avatar" let:field_state>
+///         <FileInput state=field_state accept="image/*" />
+///     </Field>
+///     <Field form=form name="attachments" let:field_state>
+///         <FileInput state=field_state multiple=true />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn FileInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    /// Allow selecting more than one file
+    #[prop(optional)]
+    multiple: bool,
+    /// File types the picker offers, e.g. `"image/*,.pdf"`
+    #[prop(into, optional)]
+    accept: Option<&'static str>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let node_ref = NodeRef::<leptos::html::Input>::new();
+
+    // Clear the selection when the field is cleared, e.g. by `Form::reset`
+    Effect::new(move |_| {
+        if value.get().is_empty() {
+            if let Some(input) = node_ref.get() {
+                input.set_value("");
+            }
+        }
+    });
+
+    let name = state.field_name.clone();
+    let on_change = move |ev: leptos::ev::Event| {
+        let input = event_target::<HtmlInputElement>(&ev);
+        let files: Vec<File> = input
+            .files()
+            .map(|list| {
+                (0..list.length())
+                    .filter_map(|index| list.get(index))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let infos: Vec<FileInfo> = files.iter().map(file_info).collect();
+        state.form.set_files(&name, files);
+        value.set(infos.to_field_value());
+    };
+
+    view! {
+        <input
+            id=id
+            node_ref=node_ref
+            type="file"
+            multiple=multiple
+            accept=accept
+            class=class
+            on:change=on_change
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
+
+/// Get the metadata of a selected file
+fn file_info(file: &File) -> FileInfo {
+    FileInfo {
+        name: file.name(),
+        size: file.size() as u64,
+        mime_type: file.type_(),
+    }
+}
@@ -1,7 +1,17 @@
 use leptos::prelude::*;
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use web_sys::js_sys;
+use web_sys::wasm_bindgen::JsCast;
 
-use super::validation::{FieldSignal, FormValidation, ValidationError};
+use super::auto_form::unwrap_type;
+use super::field_array::FieldArrayState;
+use super::validation::{
+    FieldSignal, FormValidation, FromFieldValue, ValidationError, ValidationResult, FORM_ERROR_KEY,
+};
 
 /// State object provided by FormComponent containing form values, errors, and status
 #[derive(Clone)]
@@ -12,23 +22,47 @@ pub struct FormComponentState {
     pub errors: Signal<HashMap<String, ValidationError>>,
     /// True if any field is dirty (differs from initial value)
     pub dirty: Signal<bool>,
+    /// Names of the fields that are dirty
+    pub dirty_fields: Signal<HashSet<String>>,
     /// True if any field has been touched
     pub touched: Signal<bool>,
-    /// True if form has no validation errors
+    /// Names of the fields that have been touched
+    pub touched_fields: Signal<HashSet<String>>,
+    /// True if form has no validation errors (warnings are allowed)
     pub valid: Signal<bool>,
+    /// True while async validators are running
+    pub is_validating: Signal<bool>,
+    /// True while a `Form::handle_submit` callback is running
+    pub is_submitting: Signal<bool>,
+    /// Number of submit attempts through `Form::handle_submit`
+    pub submit_count: Signal<usize>,
+    /// True if the last submit passed validation and its callback left no errors
+    pub submit_succeeded: Signal<bool>,
 }
 
 /// Internal form state that stores individual field signals
 #[derive(Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct FormState {
     /// Individual field signals - each field manages its own reactive state
     pub fields: HashMap<String, FieldSignal>,
     /// Current errors for each field
     pub errors: HashMap<String, ValidationError>,
+    /// Every current error for each field, populated when using `ErrorMode::All`
+    pub all_errors: HashMap<String, Vec<ValidationError>>,
     /// Touched state for each field
     pub touched: HashMap<String, bool>,
     /// Initial values for each field (to track dirty state)
     pub initial_values: HashMap<String, String>,
+    /// True once the whole form has been validated (typically on submit)
+    pub submitted: bool,
+    /// Fields whose async validators are still running
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub pending: HashSet<String>,
 }
 
 impl FormState {
@@ -36,8 +70,11 @@ impl FormState {
         Self {
             fields: HashMap::new(),
             errors: HashMap::new(),
+            all_errors: HashMap::new(),
             touched: HashMap::new(),
             initial_values: HashMap::new(),
+            submitted: false,
+            pending: HashSet::new(),
         }
     }
 
@@ -78,6 +115,15 @@ impl FormState {
         })
     }
 
+    /// Get the names of the dirty fields
+    pub fn dirty_fields(&self) -> HashSet<String> {
+        self.fields
+            .keys()
+            .filter(|name| self.is_field_dirty(name))
+            .cloned()
+            .collect()
+    }
+
     /// Check if a specific field is touched
     pub fn is_field_touched(&self, name: &str) -> bool {
         self.touched.get(name).copied().unwrap_or(false)
@@ -87,6 +133,137 @@ impl FormState {
     pub fn is_form_touched(&self) -> bool {
         self.touched.values().any(|&touched| touched)
     }
+
+    /// Get the names of the touched fields
+    pub fn touched_fields(&self) -> HashSet<String> {
+        self.touched
+            .iter()
+            .filter(|(_, &touched)| touched)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Check if a specific field's async validators are running
+    pub fn is_field_pending(&self, name: &str) -> bool {
+        self.pending.contains(name)
+    }
+
+    /// Check if any async validators are running
+    pub fn is_validating(&self) -> bool {
+        !self.pending.is_empty()
+    }
+}
+
+/// A copy of a form's values and state, taken with `Form::snapshot` and put
+/// back with `Form::restore`
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormSnapshot {
+    /// Value of each field
+    pub values: HashMap<String, String>,
+    /// Current error for each field
+    pub errors: HashMap<String, ValidationError>,
+    /// Every current error for each field
+    pub all_errors: HashMap<String, Vec<ValidationError>>,
+    /// Touched state for each field
+    pub touched: HashMap<String, bool>,
+    /// Initial value for each field, used to track dirty state
+    pub initial_values: HashMap<String, String>,
+    /// True if the whole form had been validated
+    pub submitted: bool,
+}
+
+/// Which DOM event updates a field's value signal
+///
+/// `Input` updates the value on every keystroke, while `Change` only updates it
+/// when the browser fires `change` (typically on blur or enter). Use `Change` for
+/// fields whose value drives expensive downstream reactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BindOn {
+    /// Update the value on every `input` event
+    #[default]
+    Input,
+    /// Update the value only on `change` events
+    Change,
+}
+
+/// When field errors are exposed through `FieldState::visible_error`
+///
+/// Validation always runs and populates `FieldState::err`; this policy only
+/// controls which of those errors are considered visible to the user.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShowErrors {
+    /// Show errors once the field has been touched or the form submitted
+    OnTouched,
+    /// Show errors only after the form has been submitted
+    OnSubmit,
+    /// Show errors as soon as validation produces them
+    #[default]
+    Always,
+}
+
+/// When a field is validated as the user edits it
+///
+/// The form uses its validate mode until it has been submitted and its
+/// revalidate mode afterwards, so errors can stay quiet while the user fills the
+/// form in and update live once they have tried to submit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidateMode {
+    /// Validate whenever the value changes
+    #[default]
+    OnChange,
+    /// Validate when the field is marked touched (typically on blur)
+    OnBlur,
+    /// Only validate when the whole form is validated
+    OnSubmit,
+}
+
+/// What `Form::reset` restores the fields to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResetMode {
+    /// Blank every field and reset the data to `FormValidation::cleared`
+    #[default]
+    Clear,
+    /// Restore the data the form was created with, like `Form::reset_to_initial`
+    Initial,
+}
+
+/// How many errors validation records per field
+///
+/// `First` stops at the first failing validator, which is all `FieldState::err`
+/// ever shows. `All` additionally runs every validator and stores the full list
+/// in `FormState::all_errors`, exposed through `FieldState::all_errors`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorMode {
+    /// Record only the first error of each field
+    #[default]
+    First,
+    /// Record every error of each field
+    All,
+}
+
+/// An async validator function, taking a snapshot of the form data
+type AsyncValidatorFn<T> =
+    Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = ValidationResult>>> + Send + Sync>;
+
+/// A rule registered with `Form::show_when`, deciding from the field values
+/// whether a field is shown
+type VisibilityRule = Arc<dyn Fn(&HashMap<String, String>) -> bool + Send + Sync>;
+
+/// An async validator registered for a field with `Form::with_async_validator`
+struct AsyncValidator<T> {
+    field_name: String,
+    validate: AsyncValidatorFn<T>,
+}
+
+// Manually implement Clone so T doesn't need to be Clone to clone the Arc
+impl<T> Clone for AsyncValidator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            field_name: self.field_name.clone(),
+            validate: Arc::clone(&self.validate),
+        }
+    }
 }
 
 /// The main form handle that users interact with
@@ -95,6 +272,48 @@ pub struct Form<T: FormValidation> {
     state: RwSignal<FormState>,
     /// Store the form data instance for validation
     form_data: RwSignal<T>,
+    /// The data the form was created with, restored by `reset_to_initial`
+    initial_data: RwSignal<T>,
+    /// Form-wide default event binding for fields
+    bind_on: RwSignal<BindOn>,
+    /// Policy deciding when field errors become visible
+    show_errors: RwSignal<ShowErrors>,
+    /// How many errors validation records per field
+    error_mode: RwSignal<ErrorMode>,
+    /// When fields are validated before the form is submitted
+    validate_mode: RwSignal<ValidateMode>,
+    /// When fields are validated after the form is submitted
+    revalidate_mode: RwSignal<ValidateMode>,
+    /// Whether fields validate as soon as they mount
+    validate_on_mount: RwSignal<bool>,
+    /// What `reset` restores the fields to
+    reset_mode: RwSignal<ResetMode>,
+    /// Async validators run by `validate_async` and `validate_field_async`
+    async_validators: StoredValue<Vec<AsyncValidator<T>>>,
+    /// Bumped whenever field values are edited or reset, so async validators
+    /// can drop results computed from values the form no longer holds
+    generation: StoredValue<u64>,
+    /// Fields to revalidate when a field is validated, from `add_dependency`
+    dependencies: StoredValue<HashMap<String, Vec<String>>>,
+    /// Rules deciding whether a field is shown, from `show_when`
+    visibility_rules: StoredValue<HashMap<String, VisibilityRule>>,
+    /// Fields mirrored into the URL query string, from `with_query_sync`
+    #[cfg(feature = "router")]
+    query_fields: StoredValue<Vec<String>>,
+    /// True while a `handle_submit` callback is running
+    is_submitting: RwSignal<bool>,
+    /// Number of submit attempts through `handle_submit`
+    submit_count: RwSignal<usize>,
+    /// Whether the last `handle_submit` attempt succeeded
+    submit_succeeded: RwSignal<bool>,
+    /// Whether a failed submit scrolls the first errored field into view
+    scroll_to_error: RwSignal<bool>,
+    /// Summary of the errors from the last failed submit, for screen readers
+    announcement: RwSignal<String>,
+    /// Files selected in `FileInput`s, by field name
+    files: StoredValue<HashMap<String, Vec<web_sys::File>>, LocalStorage>,
+    /// Field values marked invalid with `reject_value`, with their errors
+    rejected_values: StoredValue<HashMap<String, (String, ValidationError)>>,
 }
 
 // Manually implement Copy for Form<T> regardless of whether T is Copy
@@ -106,102 +325,1276 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> Form<T> {
         Self {
             state: RwSignal::new(FormState::new()),
             form_data: RwSignal::new(T::default()),
+            initial_data: RwSignal::new(T::default()),
+            bind_on: RwSignal::new(BindOn::default()),
+            show_errors: RwSignal::new(ShowErrors::default()),
+            error_mode: RwSignal::new(ErrorMode::default()),
+            validate_mode: RwSignal::new(ValidateMode::default()),
+            revalidate_mode: RwSignal::new(ValidateMode::default()),
+            validate_on_mount: RwSignal::new(true),
+            reset_mode: RwSignal::new(ResetMode::default()),
+            async_validators: StoredValue::new(Vec::new()),
+            generation: StoredValue::new(0),
+            dependencies: StoredValue::new(HashMap::new()),
+            visibility_rules: StoredValue::new(HashMap::new()),
+            #[cfg(feature = "router")]
+            query_fields: StoredValue::new(Vec::new()),
+            is_submitting: RwSignal::new(false),
+            submit_count: RwSignal::new(0),
+            submit_succeeded: RwSignal::new(false),
+            scroll_to_error: RwSignal::new(false),
+            announcement: RwSignal::new(String::new()),
+            files: StoredValue::new_local(HashMap::new()),
+            rejected_values: StoredValue::new(HashMap::new()),
         }
     }
 
-    /// Create a new form initialized with data from an existing instance
+    /// Set the form-wide default event binding for fields
+    ///
+    /// Individual fields can still override this via the `bind_on` prop on
+    /// `Field` or `Input`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from(contact).with_bind_on(BindOn::Change);
+    /// ```
+    pub fn with_bind_on(self, bind_on: BindOn) -> Self {
+        self.bind_on.set(bind_on);
+        self
+    }
+
+    /// Get the form-wide default event binding for fields
+    pub fn bind_on(&self) -> BindOn {
+        self.bind_on.get_untracked()
+    }
+
+    /// Set the policy deciding when field errors become visible
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from(contact).with_show_errors(ShowErrors::OnTouched);
+    /// ```
+    pub fn with_show_errors(self, show_errors: ShowErrors) -> Self {
+        self.show_errors.set(show_errors);
+        self
+    }
+
+    /// Get the policy deciding when field errors become visible
+    pub fn show_errors(&self) -> ShowErrors {
+        self.show_errors.get_untracked()
+    }
+
+    /// Set how many errors validation records per field
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from(signup).with_error_mode(ErrorMode::All);
+    /// ```
+    pub fn with_error_mode(self, error_mode: ErrorMode) -> Self {
+        self.error_mode.set(error_mode);
+        self
+    }
+
+    /// Get how many errors validation records per field
+    pub fn error_mode(&self) -> ErrorMode {
+        self.error_mode.get_untracked()
+    }
+
+    /// Set when fields are validated before the form is submitted
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from(signup)
+    ///     .with_validate_mode(ValidateMode::OnBlur)
+    ///     .with_revalidate_mode(ValidateMode::OnChange);
+    /// ```
+    pub fn with_validate_mode(self, validate_mode: ValidateMode) -> Self {
+        self.validate_mode.set(validate_mode);
+        self
+    }
+
+    /// Get when fields are validated before the form is submitted
+    pub fn validate_mode(&self) -> ValidateMode {
+        self.validate_mode.get_untracked()
+    }
+
+    /// Set when fields are validated after the form is submitted
+    pub fn with_revalidate_mode(self, revalidate_mode: ValidateMode) -> Self {
+        self.revalidate_mode.set(revalidate_mode);
+        self
+    }
+
+    /// Get when fields are validated after the form is submitted
+    pub fn revalidate_mode(&self) -> ValidateMode {
+        self.revalidate_mode.get_untracked()
+    }
+
+    /// Set whether fields validate as soon as they mount
+    ///
+    /// Defaults to true. Turn it off so a fresh form shows no errors (and keeps
+    /// errors seeded from the server) until the user edits a field, blurs it in
+    /// `ValidateMode::OnBlur`, or submits the form.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from(profile).with_validate_on_mount(false);
+    /// ```
+    pub fn with_validate_on_mount(self, validate_on_mount: bool) -> Self {
+        self.validate_on_mount.set(validate_on_mount);
+        self
+    }
+
+    /// Get whether fields validate as soon as they mount
+    pub fn validate_on_mount(&self) -> bool {
+        self.validate_on_mount.get_untracked()
+    }
+
+    /// Set what `reset` restores the fields to
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from(record).with_reset_mode(ResetMode::Initial);
+    /// ```
+    pub fn with_reset_mode(self, reset_mode: ResetMode) -> Self {
+        self.reset_mode.set(reset_mode);
+        self
+    }
+
+    /// Get what `reset` restores the fields to
+    pub fn reset_mode(&self) -> ResetMode {
+        self.reset_mode.get_untracked()
+    }
+
+    /// Revalidate `dependent` whenever `field_name` is validated
+    ///
+    /// This complements `#[field(depends_on = "...")]` for dependencies only
+    /// known at runtime.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::new().add_dependency(SignupForm::PASSWORD, SignupForm::CONFIRM_PASSWORD);
+    /// ```
+    pub fn add_dependency(
+        self,
+        field_name: impl Into<String>,
+        dependent: impl Into<String>,
+    ) -> Self {
+        let dependent = dependent.into();
+        self.dependencies.update_value(|dependencies| {
+            let dependents = dependencies.entry(field_name.into()).or_default();
+            if !dependents.contains(&dependent) {
+                dependents.push(dependent);
+            }
+        });
+        self
+    }
+
+    /// Only show a field while `rule` holds for the current field values
+    ///
+    /// While hidden, the field is skipped by validation, its errors are
+    /// dropped, and its value isn't synced to the typed data. Wrap the field in
+    /// a `ShowField` to hide it from the page as well.
+    ///
+    /// The rule sees the value of every field of the typed data, taken from
+    /// its input while one is mounted. Other keys, like those of field array
+    /// items that were never registered, may be missing, so look values up
+    /// with `get` rather than indexing.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::new().show_when("company_name", |values| {
+    ///     values.get("is_business").is_some_and(|v| v == "true")
+    /// });
+    /// ```
+    pub fn show_when<F>(self, field_name: impl Into<String>, rule: F) -> Self
+    where
+        F: Fn(&HashMap<String, String>) -> bool + Send + Sync + 'static,
+    {
+        self.visibility_rules.update_value(|rules| {
+            rules.insert(field_name.into(), Arc::new(rule));
+        });
+        self
+    }
+
+    /// Check whether a field is shown under its `show_when` rule
+    ///
+    /// This tracks the field values, so it updates when read inside a reactive
+    /// closure. Fields without a rule are always shown.
+    pub fn is_field_visible(&self, field_name: &str) -> bool {
+        let rule = self
+            .visibility_rules
+            .with_value(|rules| rules.get(field_name).cloned());
+        match rule {
+            Some(rule) => {
+                let mut values = self.form_data.with(T::to_strings);
+                self.state.with(|state| {
+                    for (name, field) in state.fields.iter() {
+                        values.insert(name.clone(), field.value.get());
+                    }
+                });
+                rule(&values)
+            }
+            None => true,
+        }
+    }
+
+    /// Check whether a field is hidden by its `show_when` rule, without
+    /// tracking the field values
+    fn is_field_hidden(&self, field_name: &str) -> bool {
+        let rule = self
+            .visibility_rules
+            .with_value(|rules| rules.get(field_name).cloned());
+        rule.is_some_and(|rule| {
+            let mut values = self.form_data.with_untracked(T::to_strings);
+            values.extend(self.values());
+            !rule(&values)
+        })
+    }
+
+    /// Get the fields currently hidden by their `show_when` rule
+    fn hidden_fields(&self) -> HashSet<String> {
+        let names: Vec<_> = self
+            .visibility_rules
+            .with_value(|rules| rules.keys().cloned().collect());
+        names
+            .into_iter()
+            .filter(|name| self.is_field_hidden(name))
+            .collect()
+    }
+
+    /// Mirror fields into the URL query string, e.g. for filter and search forms
+    ///
+    /// When a `FormComponent` for the form mounts, these fields are set from
+    /// the query string, and from then on the URL is updated in place as they
+    /// change, so `?status=open&min_age=18` can be shared or bookmarked.
+    /// Empty fields are left out of the URL. The form must be rendered under a
+    /// leptos_router `Router`. Requires the `router` feature.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::<FilterForm>::new().with_query_sync(["status", "min_age"]);
+    /// ```
+    #[cfg(feature = "router")]
+    pub fn with_query_sync<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.query_fields
+            .set_value(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Get the fields mirrored into the URL query string
+    #[cfg(feature = "router")]
+    pub fn query_fields(&self) -> Vec<String> {
+        self.query_fields.get_value()
+    }
+
+    /// Set whether a failed `handle_submit` scrolls the first errored field
+    /// into view (default: false)
+    ///
+    /// The field is found by its element id, which must be the field name as
+    /// with `Label`.
+    pub fn with_scroll_to_error(self, scroll_to_error: bool) -> Self {
+        self.scroll_to_error.set(scroll_to_error);
+        self
+    }
+
+    /// Get whether a failed submit scrolls the first errored field into view
+    pub fn scroll_to_error(&self) -> bool {
+        self.scroll_to_error.get_untracked()
+    }
+
+    /// Get the validate mode currently in effect, depending on whether the
+    /// form has been submitted
+    pub fn active_validate_mode(&self) -> ValidateMode {
+        if self.state.with_untracked(|state| state.submitted) {
+            self.revalidate_mode()
+        } else {
+            self.validate_mode()
+        }
+    }
+
+    /// Register an async validator for a field, e.g. a server-side uniqueness check
+    ///
+    /// The validator receives a snapshot of the form data and runs in
+    /// `validate_async` and `validate_field_async`, after the synchronous
+    /// validators have passed for the field.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::<Signup>::new().with_async_validator("username", |data: Signup| async move {
+    ///     if username_taken(&data.username).await {
+    ///         Err(ValidationError::new("username", "Username is taken"))
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// ```
+    pub fn with_async_validator<F, Fut>(self, field_name: impl Into<String>, validate: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ValidationResult> + 'static,
+    {
+        let validate: AsyncValidatorFn<T> = Arc::new(move |data| {
+            Box::pin(validate(data)) as Pin<Box<dyn Future<Output = ValidationResult>>>
+        });
+        let field_name = field_name.into();
+        self.async_validators.update_value(|validators| {
+            validators.push(AsyncValidator {
+                field_name,
+                validate,
+            });
+        });
+        self
+    }
+
+    /// Create a new form initialized with data from an existing instance
+    ///
+    /// This method allows you to populate a form with existing data, converting
+    /// the typed fields to strings for the form inputs.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let contact = Contact {
+    ///     name: "Jed".to_string(),
+    ///     email: "jed@email.com".to_string(),
+    /// };
+    /// let form = Form::from(contact);
+    /// ```
+    pub fn from(data: T) -> Self {
+        let string_values = data.to_strings();
+        let mut form_state = FormState::new();
+
+        // Initialize field signals with the string values
+        for (field_name, value) in string_values.iter() {
+            let field_signal = form_state.get_or_create_field(field_name);
+            field_signal.value.set(value.clone());
+            // Set initial value for dirty tracking
+            form_state
+                .initial_values
+                .insert(field_name.clone(), value.clone());
+        }
+
+        Self {
+            state: RwSignal::new(form_state),
+            form_data: RwSignal::new(data.clone()),
+            initial_data: RwSignal::new(data),
+            bind_on: RwSignal::new(BindOn::default()),
+            show_errors: RwSignal::new(ShowErrors::default()),
+            error_mode: RwSignal::new(ErrorMode::default()),
+            validate_mode: RwSignal::new(ValidateMode::default()),
+            revalidate_mode: RwSignal::new(ValidateMode::default()),
+            validate_on_mount: RwSignal::new(true),
+            reset_mode: RwSignal::new(ResetMode::default()),
+            async_validators: StoredValue::new(Vec::new()),
+            generation: StoredValue::new(0),
+            dependencies: StoredValue::new(HashMap::new()),
+            visibility_rules: StoredValue::new(HashMap::new()),
+            #[cfg(feature = "router")]
+            query_fields: StoredValue::new(Vec::new()),
+            is_submitting: RwSignal::new(false),
+            submit_count: RwSignal::new(0),
+            submit_succeeded: RwSignal::new(false),
+            scroll_to_error: RwSignal::new(false),
+            announcement: RwSignal::new(String::new()),
+            files: StoredValue::new_local(HashMap::new()),
+            rejected_values: StoredValue::new(HashMap::new()),
+        }
+    }
+
+    /// Create a form from JSON produced by `Form::to_json`
+    ///
+    /// The field values, errors, touched flags, initial values and submitted
+    /// flag are restored as they were saved, so a restored draft still shows
+    /// which fields are dirty or invalid. Requires the `serde` feature.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = match load_draft() {
+    ///     Some(json) => Form::<Contact>::from_json(&json)?,
+    ///     None => Form::new(),
+    /// };
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let form = Self::new();
+        form.load_json(json)?;
+        Ok(form)
+    }
+
+    /// Restore JSON produced by `Form::to_json` into this form
+    ///
+    /// Like `Form::from_json`, but keeps the form's settings and starts from
+    /// its `FormValidation::cleared` data, so forms whose data carries more
+    /// than its values, like `Form::from_schema`, can restore a draft.
+    /// Requires the `serde` feature.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form = Form::from_schema(schema);
+    /// if let Some(json) = load_draft() {
+    ///     form.load_json(&json)?;
+    /// }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn load_json(&self, json: &str) -> Result<(), serde_json::Error> {
+        let form_state: FormState = serde_json::from_str(json)?;
+
+        // The initial values become the data `reset_to_initial` restores
+        let initial_fields: HashMap<_, _> = form_state
+            .initial_values
+            .iter()
+            .map(|(name, value)| {
+                let field = FieldSignal {
+                    value: RwSignal::new(value.clone()),
+                };
+                (name.clone(), field)
+            })
+            .collect();
+        let mut initial_data = self.form_data.with_untracked(T::cleared);
+        initial_data.sync_from_strings(&initial_fields);
+
+        self.files.update_value(HashMap::clear);
+        self.rejected_values.update_value(HashMap::clear);
+        self.form_data.set(initial_data.clone());
+        self.initial_data.set(initial_data);
+        self.state.set(form_state);
+        self.sync_to_form_data();
+        self.bump_generation();
+        Ok(())
+    }
+
+    /// Serialize the form's values and state to JSON
+    ///
+    /// Besides the field values this includes errors, touched flags, initial
+    /// values and the submitted flag, so a draft can be persisted or sent to
+    /// the server and restored with `Form::from_json`. Requires the `serde`
+    /// feature.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// save_draft(form.to_json()?);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        self.state.with_untracked(serde_json::to_string)
+    }
+
+    /// Validate all fields using the form struct's validation
+    pub fn validate(&self) -> bool {
+        // Sync current field values to form_data
+        let parse_errors = self.sync_to_form_data();
+
+        // If there were parse errors, add them to state and return false
+        if !parse_errors.is_empty() {
+            let all_errors = self.collect_all_errors(&parse_errors, false);
+            self.state.update(|state| {
+                state.submitted = true;
+                state.errors.extend(parse_errors);
+                state.all_errors.extend(all_errors);
+            });
+            return false;
+        }
+
+        // Run validation on form_data, skipping hidden fields
+        let mut validation_errors = self.form_data.get_untracked().validate_all();
+        let hidden = self.hidden_fields();
+        validation_errors.retain(|name, _| !hidden.contains(name));
+
+        // Rejected values fail like a validator, unless a hard error is
+        // already reported for the field
+        let rejected: Vec<String> = self
+            .rejected_values
+            .with_value(|rejected| rejected.keys().cloned().collect());
+        for name in rejected {
+            let has_error = validation_errors
+                .get(&name)
+                .is_some_and(|error| !error.is_warning());
+            if hidden.contains(&name) || has_error {
+                continue;
+            }
+            if let Some(error) = self.rejection(&name) {
+                validation_errors.insert(name, error);
+            }
+        }
+        let all_errors = self.collect_all_errors(&validation_errors, true);
+
+        // Update state with errors
+        self.state.update(|state| {
+            state.submitted = true;
+            state.errors = validation_errors;
+            state.all_errors = all_errors;
+        });
+
+        // Return true if no errors, warnings don't block submission
+        self.state
+            .get_untracked()
+            .errors
+            .values()
+            .all(ValidationError::is_warning)
+    }
+
+    /// Validate the form and return the typed data, or the errors if invalid
+    ///
+    /// This syncs the field values, validates them like `validate`, and only
+    /// hands out the data when it is fresh and valid, unlike `data` which
+    /// returns whatever was last synced. Warnings don't make the form invalid.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// match form.data_validated() {
+    ///     Ok(contact) => save_contact(contact),
+    ///     Err(errors) => log!("{} fields are invalid", errors.len()),
+    /// }
+    /// ```
+    pub fn data_validated(&self) -> Result<T, HashMap<String, ValidationError>> {
+        if self.validate() {
+            Ok(self.data())
+        } else {
+            Err(self.state.with_untracked(|state| state.errors.clone()))
+        }
+    }
+
+    /// Validate all fields, including async validators
+    ///
+    /// Runs `validate` first, then the async validators of every field without
+    /// a blocking error, one after another. While they run, the fields are
+    /// marked pending and `FormComponentState::is_validating` is true.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// spawn_local(async move {
+    ///     if form.validate_async().await {
+    ///         save(form.data()).await;
+    ///     }
+    /// });
+    /// ```
+    pub async fn validate_async(&self) -> bool {
+        let valid = self.validate();
+        let async_valid = self.run_async_validators(None).await;
+        valid && async_valid
+    }
+
+    /// Create a submit event handler for the form element
+    ///
+    /// The handler prevents the default form submission, then validates the
+    /// form including its async validators. If the form is valid, `on_submit`
+    /// runs with the typed data; otherwise every field is marked touched so its
+    /// error shows. Submits while a previous one is running are ignored.
+    ///
+    /// The submit succeeds if the form is still valid after `on_submit`
+    /// finishes, so a callback reporting server errors with `set_errors` marks
+    /// it as failed.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let on_submit = form.handle_submit(|data: ContactForm| async move {
+    ///     save_contact(data).await;
+    /// });
+    ///
+    /// view! {
+    ///     <form on:submit=on_submit>
+    ///         // ...
+    ///         <button type="submit" disabled=move || form.is_submitting().get()>
+    ///             "Save"
+    ///         </button>
+    ///     </form>
+    /// }
+    /// ```
+    pub fn handle_submit<F, Fut>(
+        &self,
+        on_submit: F,
+    ) -> impl Fn(leptos::ev::SubmitEvent) + Clone + 'static
+    where
+        F: Fn(T) -> Fut + Clone + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let form = *self;
+        move |event: leptos::ev::SubmitEvent| {
+            event.prevent_default();
+            if form.is_submitting.get_untracked() {
+                return;
+            }
+
+            form.is_submitting.set(true);
+            form.submit_succeeded.set(false);
+            form.submit_count.update(|count| *count += 1);
+            let on_submit = on_submit.clone();
+            leptos::task::spawn_local(async move {
+                let succeeded = if form.validate_async().await {
+                    on_submit(form.data()).await;
+                    form.state.with_untracked(|state| {
+                        state.errors.values().all(ValidationError::is_warning)
+                    })
+                } else {
+                    form.report_errors();
+                    false
+                };
+                if succeeded {
+                    form.announcement.set(String::new());
+                }
+                form.submit_succeeded.set(succeeded);
+                form.is_submitting.set(false);
+            });
+        }
+    }
+
+    /// Point the user at the errors after a failed submit
+    ///
+    /// Every field is marked touched so its error shows, the errors are
+    /// summarized for `ErrorAnnouncer`, and the first errored field is
+    /// scrolled into view if `with_scroll_to_error` is set.
+    fn report_errors(&self) {
+        self.mark_all_touched();
+
+        let count = self.state.with_untracked(|state| {
+            state
+                .errors
+                .values()
+                .filter(|error| !error.is_warning())
+                .count()
+        });
+        self.announcement.set(match count {
+            0 => String::new(),
+            1 => "1 field needs attention".to_string(),
+            count => format!("{} fields need attention", count),
+        });
+
+        if self.scroll_to_error() {
+            self.scroll_to_first_error();
+        }
+    }
+
+    /// Get the name of the first field with an error, ignoring warnings
+    ///
+    /// Fields are taken in the order they are declared in the form struct,
+    /// followed by any other errored fields, such as field array items, by name.
+    pub fn first_error_field(&self) -> Option<String> {
+        let field_names = self.form_data.with_untracked(T::data_field_names);
+        self.state.with_untracked(|state| {
+            let errored = |name: &str| {
+                name != FORM_ERROR_KEY
+                    && state
+                        .errors
+                        .get(name)
+                        .is_some_and(|error| !error.is_warning())
+            };
+            let declared = field_names.into_iter().find(|name| errored(name));
+            declared.or_else(|| {
+                state
+                    .errors
+                    .keys()
+                    .filter(|name| errored(name))
+                    .min()
+                    .cloned()
+            })
+        })
+    }
+
+    /// Scroll the first field with an error into view
+    ///
+    /// The field is found by its element id, which the controls default to
+    /// the field name, or else by the first element whose `name` attribute is
+    /// the field name. Nothing happens if no field has an error or no element
+    /// is found.
+    pub fn scroll_to_first_error(&self) {
+        let Some(field_name) = self.first_error_field() else {
+            return;
+        };
+        let document = document();
+        let element = document.get_element_by_id(&field_name).or_else(|| {
+            let escaped = field_name.replace('\\', "\\\\").replace('"', "\\\"");
+            document
+                .query_selector(&format!("[name=\"{}\"]", escaped))
+                .ok()
+                .flatten()
+        });
+        if let Some(element) = element {
+            element.scroll_into_view();
+        }
+    }
+
+    /// Get a signal with a summary of the errors from the last failed submit,
+    /// such as "3 fields need attention"
+    ///
+    /// It is empty until a submit fails, and cleared when one succeeds.
+    /// `ErrorAnnouncer` reads it out to screen readers.
+    pub fn announcement(&self) -> Signal<String> {
+        self.announcement.into()
+    }
+
+    /// Get a signal that is true while a `handle_submit` callback is running
+    pub fn is_submitting(&self) -> Signal<bool> {
+        self.is_submitting.into()
+    }
+
+    /// Get a signal counting the submit attempts through `handle_submit`,
+    /// including attempts that failed validation
+    pub fn submit_count(&self) -> Signal<usize> {
+        self.submit_count.into()
+    }
+
+    /// Get a signal that is true if the last `handle_submit` attempt passed
+    /// validation and its callback left no errors, e.g. to show a "Saved!" toast
+    pub fn submit_succeeded(&self) -> Signal<bool> {
+        self.submit_succeeded.into()
+    }
+
+    /// Validate a single field by name, including its async validators
+    ///
+    /// The async validators only run when the synchronous ones pass, and the
+    /// field is marked pending while they run.
+    pub async fn validate_field_async(&self, field_name: &str) {
+        self.validate_field(field_name);
+        self.run_async_validators(Some(field_name)).await;
+    }
+
+    /// Run the async validators of `field_name`, or of every field if `None`
+    ///
+    /// Fields that already have a blocking error are skipped. A blocking async
+    /// error replaces a warning the field already has. If the field values are
+    /// edited or reset while a validator runs, its result is dropped and the
+    /// run stops. Returns false if any async validator reported a blocking
+    /// error or the run was dropped.
+    async fn run_async_validators(&self, field_name: Option<&str>) -> bool {
+        let validators: Vec<_> = self.async_validators.with_value(|validators| {
+            validators
+                .iter()
+                .filter(|v| field_name.is_none_or(|name| v.field_name == name))
+                .cloned()
+                .collect()
+        });
+        let hidden = self.hidden_fields();
+        let validators: Vec<_> = self.state.with_untracked(|state| {
+            validators
+                .into_iter()
+                .filter(|v| {
+                    !hidden.contains(&v.field_name)
+                        && state
+                            .errors
+                            .get(&v.field_name)
+                            .is_none_or(ValidationError::is_warning)
+                })
+                .collect()
+        });
+        if validators.is_empty() {
+            return true;
+        }
+
+        self.state.update(|state| {
+            for validator in &validators {
+                state.pending.insert(validator.field_name.clone());
+            }
+        });
+
+        let data = self.form_data.get_untracked();
+        let generation = self.generation.get_value();
+        let mut valid = true;
+        for (index, validator) in validators.iter().enumerate() {
+            let result = (validator.validate)(data.clone()).await;
+
+            // The result describes values the form no longer holds
+            if self.generation.get_value() != generation {
+                self.state.update(|state| {
+                    for validator in &validators[index..] {
+                        state.pending.remove(&validator.field_name);
+                    }
+                });
+                return false;
+            }
+
+            // A field stays pending until its last validator finishes
+            let done = !validators[index + 1..]
+                .iter()
+                .any(|v| v.field_name == validator.field_name);
+            self.state.update(|state| {
+                if done {
+                    state.pending.remove(&validator.field_name);
+                }
+                if let Err(error) = result {
+                    valid &= error.is_warning();
+                    match state.errors.entry(validator.field_name.clone()) {
+                        Entry::Occupied(mut entry)
+                            if entry.get().is_warning() && !error.is_warning() =>
+                        {
+                            entry.insert(error);
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(error);
+                        }
+                        Entry::Occupied(_) => {}
+                    }
+                }
+            });
+        }
+        valid
+    }
+
+    /// Record that field values were edited or reset, so running async
+    /// validators drop their results
+    pub(crate) fn bump_generation(&self) {
+        self.generation.update_value(|generation| *generation += 1);
+    }
+
+    /// Build the per-field error lists for `ErrorMode::All`
+    ///
+    /// With `run_validators`, each failing field's validators are re-run to
+    /// collect every error; otherwise (e.g. parse errors) the single error is
+    /// used as is. Returns an empty map in `ErrorMode::First`.
+    fn collect_all_errors(
+        &self,
+        errors: &HashMap<String, ValidationError>,
+        run_validators: bool,
+    ) -> HashMap<String, Vec<ValidationError>> {
+        if self.error_mode() != ErrorMode::All {
+            return HashMap::new();
+        }
+
+        let data = self.form_data.get_untracked();
+        errors
+            .iter()
+            .map(|(name, error)| {
+                let mut field_errors = if run_validators {
+                    data.validate_field_all(name)
+                } else {
+                    Vec::new()
+                };
+                // Form-level errors and rejected values are not produced by the
+                // field's validators
+                if field_errors.is_empty()
+                    || (!error.is_warning() && field_errors.iter().all(ValidationError::is_warning))
+                {
+                    field_errors.insert(0, error.clone());
+                }
+                (name.clone(), field_errors)
+            })
+            .collect()
+    }
+
+    /// Sync field values from signals to the form data struct
+    /// Returns parse errors for fields that couldn't be converted
+    pub(crate) fn sync_to_form_data(&self) -> HashMap<String, ValidationError> {
+        let mut fields = self.state.get_untracked().fields;
+        // Hidden fields keep their previous typed value
+        let hidden = self.hidden_fields();
+        fields.retain(|name, _| !hidden.contains(name));
+        self.form_data.update_untracked(|data| {
+            // This will be implemented by the derive macro
+            // to parse strings into appropriate types
+            data.sync_from_strings(&fields)
+        })
+    }
+
+    /// Reset all form values and errors
+    ///
+    /// By default this blanks every field. With `ResetMode::Initial` it
+    /// restores the data the form was created with instead.
+    pub fn reset(&self) {
+        if self.reset_mode() == ResetMode::Initial {
+            self.reset_to_initial();
+            return;
+        }
+
+        self.state.update(|state| {
+            for field in state.fields.values() {
+                field.value.set(String::new());
+            }
+            state.errors.clear();
+            state.all_errors.clear();
+            state.touched.clear();
+            state.pending.clear();
+            state.submitted = false;
+            // Reset initial values to empty strings
+            for initial in state.initial_values.values_mut() {
+                *initial = String::new();
+            }
+        });
+        self.files.update_value(HashMap::clear);
+        self.rejected_values.update_value(HashMap::clear);
+        let cleared = self.form_data.with_untracked(T::cleared);
+        self.form_data.set(cleared);
+        self.bump_generation();
+    }
+
+    /// Reset all form values and errors to the data the form was created with
+    ///
+    /// For a form created with `Form::from(record)` this restores `record`,
+    /// and the fields are no longer dirty. For `Form::new` it matches `reset`.
+    pub fn reset_to_initial(&self) {
+        let data = self.initial_data.get_untracked();
+        let initial_values = data.to_strings();
+        self.state.update(|state| {
+            for (name, field) in state.fields.iter() {
+                let initial = initial_values.get(name).cloned().unwrap_or_default();
+                field.value.set(initial.clone());
+                state.initial_values.insert(name.clone(), initial);
+            }
+            state.errors.clear();
+            state.all_errors.clear();
+            state.touched.clear();
+            state.pending.clear();
+            state.submitted = false;
+        });
+        self.files.update_value(HashMap::clear);
+        self.rejected_values.update_value(HashMap::clear);
+        self.form_data.set(data);
+        self.bump_generation();
+    }
+
+    /// Mark every field as touched
+    ///
+    /// Call this after a failed submit so every field with an error shows it,
+    /// including fields the user never focused.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// if !form.validate() {
+    ///     form.mark_all_touched();
+    ///     return;
+    /// }
+    /// ```
+    pub fn mark_all_touched(&self) {
+        let names = self.form_data.with_untracked(T::data_field_names);
+        self.state.update(|state| {
+            let registered = state.fields.keys().cloned().collect::<Vec<_>>();
+            for name in names.into_iter().chain(registered) {
+                state.touched.insert(name, true);
+            }
+        });
+    }
+
+    /// Take a snapshot of the form's values, errors, touched flags and
+    /// initial values
+    ///
+    /// Put it back with `restore`, e.g. to discard the changes made in a modal
+    /// since it was opened.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let snapshot = StoredValue::new(form.snapshot());
+    /// let discard = move |_| form.restore(&snapshot.get_value());
+    /// ```
+    pub fn snapshot(&self) -> FormSnapshot {
+        self.state.with_untracked(|state| FormSnapshot {
+            values: state
+                .fields
+                .iter()
+                .map(|(name, field)| (name.clone(), field.value.get_untracked()))
+                .collect(),
+            errors: state.errors.clone(),
+            all_errors: state.all_errors.clone(),
+            touched: state.touched.clone(),
+            initial_values: state.initial_values.clone(),
+            submitted: state.submitted,
+        })
+    }
+
+    /// Restore the form to a snapshot taken with `snapshot`
+    ///
+    /// Fields registered since the snapshot was taken are cleared.
+    pub fn restore(&self, snapshot: &FormSnapshot) {
+        self.state.update(|state| {
+            for (name, field) in state.fields.iter() {
+                let value = snapshot.values.get(name).cloned().unwrap_or_default();
+                field.value.set(value);
+            }
+            for (name, value) in snapshot.values.iter() {
+                if !state.fields.contains_key(name) {
+                    let field = state.get_or_create_field(name);
+                    field.value.set(value.clone());
+                }
+            }
+            state.errors = snapshot.errors.clone();
+            state.all_errors = snapshot.all_errors.clone();
+            state.touched = snapshot.touched.clone();
+            state.initial_values = snapshot.initial_values.clone();
+            state.pending.clear();
+            state.submitted = snapshot.submitted;
+        });
+        self.sync_to_form_data();
+        self.bump_generation();
+    }
+
+    /// Get current form values as a map of strings
+    pub fn values(&self) -> HashMap<String, String> {
+        let state = self.state.get_untracked();
+        state
+            .fields
+            .iter()
+            .map(|(name, field)| (name.clone(), field.value.get_untracked()))
+            .collect()
+    }
+
+    /// Get the names of the fields whose value differs from their initial value
+    ///
+    /// This tracks the form state, so it updates when read inside a reactive
+    /// closure.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let changed = move || form.dirty_fields().contains(ProfileForm::EMAIL);
+    /// ```
+    pub fn dirty_fields(&self) -> HashSet<String> {
+        self.state.with(FormState::dirty_fields)
+    }
+
+    /// Get the names of the fields that have been touched
+    ///
+    /// This tracks the form state, so it updates when read inside a reactive
+    /// closure.
+    pub fn touched_fields(&self) -> HashSet<String> {
+        self.state.with(FormState::touched_fields)
+    }
+
+    /// Get only the values that differ from their initial value
+    ///
+    /// Useful for PATCH-style submits that send just the changed fields. The
+    /// initial values are those from `Form::from`, or empty for `Form::new`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let changes = form.changed_values();
+    /// if !changes.is_empty() {
+    ///     patch_profile(user_id, changes).await?;
+    /// }
+    /// ```
+    pub fn changed_values(&self) -> HashMap<String, String> {
+        self.state.with_untracked(|state| {
+            state
+                .fields
+                .iter()
+                .filter(|(name, _)| state.is_field_dirty(name))
+                .map(|(name, field)| (name.clone(), field.value.get_untracked()))
+                .collect()
+        })
+    }
+
+    /// Get a handle to the repeated group of fields of a `#[field(array)]` field
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// form.field_array(Contact::PHONES).push(&Phone::default());
+    /// ```
+    pub fn field_array(&self, name: impl Into<Oco<'static, str>>) -> FieldArrayState<T> {
+        FieldArrayState::new(*self, name.into())
+    }
+
+    /// Get the value signal of a field by name, registering the field if needed
+    pub fn field_value(&self, field_name: &str) -> RwSignal<String> {
+        self.state
+            .update_untracked(|state| state.get_or_create_field(field_name))
+            .value
+    }
+
+    /// Set a field's value by name, e.g. from a "use my account email" button
+    ///
+    /// Dirty state is recomputed. A mounted `Field` validates the new value
+    /// according to its validate mode; use `set_field_value` to always validate.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// form.set_value(ContactForm::EMAIL, account.email.clone());
+    /// ```
+    pub fn set_value(&self, field_name: &str, value: impl Into<String>) {
+        self.field_value(field_name).set(value.into());
+        self.bump_generation();
+        // Notify subscribers of the form state, which derive dirty state from it
+        self.state.update(|_| {});
+    }
+
+    /// Set a field's value by name from a typed value
     ///
-    /// This method allows you to populate a form with existing data, converting
-    /// the typed fields to strings for the form inputs.
+    /// The value is converted with `FromFieldValue::to_field_value`, as when the
+    /// form is created with `Form::from`.
     ///
     /// # Example
     /// ```rust,ignore
-    /// let contact = Contact {
-    ///     name: "Jed".to_string(),
-    ///     email: "jed@email.com".to_string(),
-    /// };
-    /// let form = Form::from(contact);
+    /// form.set_typed_value(OrderForm::QUANTITY, &3u32);
     /// ```
-    pub fn from(data: T) -> Self {
-        let string_values = data.to_strings();
-        let mut form_state = FormState::new();
+    pub fn set_typed_value<V: FromFieldValue>(&self, field_name: &str, value: &V) {
+        self.set_value(field_name, value.to_field_value());
+    }
 
-        // Initialize field signals with the string values
-        for (field_name, value) in string_values.iter() {
-            let field_signal = form_state.get_or_create_field(field_name);
-            field_signal.value.set(value.clone());
-            // Set initial value for dirty tracking
-            form_state
-                .initial_values
-                .insert(field_name.clone(), value.clone());
+    /// Set several field values by name at once
+    ///
+    /// Like `set_value`, this doesn't validate; mounted fields validate
+    /// according to their validate mode.
+    pub fn set_values<K, V>(&self, values: impl IntoIterator<Item = (K, V)>)
+    where
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        for (field_name, value) in values {
+            self.field_value(field_name.as_ref()).set(value.into());
         }
+        self.bump_generation();
+        // Notify subscribers of the form state, which derive dirty state from it
+        self.state.update(|_| {});
+    }
 
-        Self {
-            state: RwSignal::new(form_state),
-            form_data: RwSignal::new(data),
-        }
+    /// Set field values from a submitted `<form>`'s `FormData`
+    ///
+    /// Entries are matched to fields by their `name` attribute, so inputs
+    /// named like the form's fields fill them in. The values of a `Vec` field,
+    /// as from a checkbox group, are escaped and joined into the
+    /// comma-separated list it parses, even when there is only one. Other
+    /// fields take a single value as it is, and join repeated ones the same
+    /// way. File entries are skipped.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let form_data = web_sys::FormData::new_with_form(&form_element)?;
+    /// form.set_values_from_form_data(&form_data);
+    /// ```
+    pub fn set_values_from_form_data(&self, form_data: &web_sys::FormData) {
+        let values = form_data.entries().into_iter().filter_map(|entry| {
+            let entry = entry.ok()?.dyn_into::<js_sys::Array>().ok()?;
+            Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+        });
+        self.set_values(join_repeated(values, Self::is_list_field));
     }
 
-    /// Validate all fields using the form struct's validation
-    pub fn validate(&self) -> bool {
-        // Sync current field values to form_data
-        let parse_errors = self.sync_to_form_data();
+    /// Set field values from an `application/x-www-form-urlencoded` string
+    ///
+    /// This hydrates the form from a no-JS form submission body or from a
+    /// query string, with or without its leading `?`. Values are joined into
+    /// lists as with `set_values_from_form_data`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// form.set_values_from_url_encoded("name=Jed&email=jed%40email.com");
+    /// ```
+    pub fn set_values_from_url_encoded(&self, input: &str) {
+        self.set_values(join_repeated(parse_url_encoded(input), Self::is_list_field));
+    }
 
-        // If there were parse errors, add them to state and return false
-        if !parse_errors.is_empty() {
-            self.state.update(|state| {
-                state.errors.extend(parse_errors);
-            });
-            return false;
-        }
+    /// Check whether a field of the form's struct holds a `Vec`
+    fn is_list_field(field_name: &str) -> bool {
+        T::field_meta().iter().any(|meta| {
+            let type_name = unwrap_type(meta.type_name, "Option");
+            meta.name == field_name && unwrap_type(type_name, "Vec") != type_name
+        })
+    }
 
-        // Run validation on form_data
-        let validation_errors = self.form_data.get_untracked().validate_all();
+    /// Get the files selected in a field's `FileInput`, e.g. to upload them on
+    /// submit
+    ///
+    /// The field's value only holds each file's `FileInfo`, so this is where
+    /// the contents are read from.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// for file in form.files("avatar") {
+    ///     upload(file).await;
+    /// }
+    /// ```
+    pub fn files(&self, field_name: &str) -> Vec<web_sys::File> {
+        self.files
+            .with_value(|files| files.get(field_name).cloned().unwrap_or_default())
+    }
 
-        // Update state with errors
-        self.state.update(|state| {
-            state.errors = validation_errors;
+    /// Store the files selected in a field's `FileInput`
+    pub(crate) fn set_files(&self, field_name: &str, files: Vec<web_sys::File>) {
+        self.files.update_value(|all| {
+            all.insert(field_name.to_string(), files);
         });
+    }
 
-        // Return true if no errors
-        self.state.get_untracked().errors.is_empty()
+    /// Set a field's value by name and validate it
+    pub fn set_field_value(&self, field_name: &str, value: impl Into<String>) {
+        self.field_value(field_name).set(value.into());
+        self.bump_generation();
+        self.validate_field(field_name);
     }
 
-    /// Sync field values from signals to the form data struct
-    /// Returns parse errors for fields that couldn't be converted
-    fn sync_to_form_data(&self) -> HashMap<String, ValidationError> {
-        let state = self.state.get_untracked();
-        self.form_data.update_untracked(|data| {
-            // This will be implemented by the derive macro
-            // to parse strings into appropriate types
-            data.sync_from_strings(&state.fields)
-        })
+    /// Replace the form's errors, e.g. with errors returned by the server
+    ///
+    /// The errors show through `FieldState::err` like validation errors, and a
+    /// field's error is replaced once it is validated again. Form-level errors
+    /// go under `FORM_ERROR_KEY`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// if let Err(errors) = save_contact(form.data()).await {
+    ///     form.set_errors(errors);
+    /// }
+    /// ```
+    pub fn set_errors(&self, errors: HashMap<String, ValidationError>) {
+        let error_mode = self.error_mode();
+        self.state.update(|state| {
+            state.all_errors.clear();
+            if error_mode == ErrorMode::All {
+                for (name, error) in &errors {
+                    state.all_errors.insert(name.clone(), vec![error.clone()]);
+                }
+            }
+            state.errors = errors;
+        });
     }
 
-    /// Reset all form values and errors
-    pub fn reset(&self) {
+    /// Set a single field's error, e.g. "email already taken" from the server
+    pub fn set_field_error(&self, field_name: &str, error: ValidationError) {
+        let error_mode = self.error_mode();
         self.state.update(|state| {
-            for field in state.fields.values() {
-                field.value.set(String::new());
+            if error_mode == ErrorMode::All {
+                state
+                    .all_errors
+                    .insert(field_name.to_string(), vec![error.clone()]);
             }
+            state.errors.insert(field_name.to_string(), error);
+        });
+    }
+
+    /// Clear every error without revalidating
+    pub fn clear_errors(&self) {
+        self.state.update(|state| {
             state.errors.clear();
-            state.touched.clear();
-            // Reset initial values to empty strings
-            for initial in state.initial_values.values_mut() {
-                *initial = String::new();
-            }
+            state.all_errors.clear();
         });
-        self.form_data.set(T::default());
     }
 
-    /// Get current form values as a map of strings
-    pub fn values(&self) -> HashMap<String, String> {
-        let state = self.state.get_untracked();
-        state
-            .fields
-            .iter()
-            .map(|(name, field)| (name.clone(), field.value.get_untracked()))
-            .collect()
+    /// Clear a single field's error without revalidating, e.g. to dismiss a
+    /// stale server error once the user edits the field again
+    pub fn clear_field_error(&self, field_name: &str) {
+        self.state.update(|state| {
+            state.errors.remove(field_name);
+            state.all_errors.remove(field_name);
+        });
+    }
+
+    /// Mark a field's value as invalid for as long as the field holds it, e.g.
+    /// free text typed into a strict `Combobox`
+    ///
+    /// `validate` and field validation report the error like a validator's,
+    /// though the field's own hard errors take precedence.
+    pub fn reject_value(&self, field_name: &str, value: impl Into<String>, error: ValidationError) {
+        self.rejected_values.update_value(|rejected| {
+            rejected.insert(field_name.to_string(), (value.into(), error));
+        });
+    }
+
+    /// Forget a value rejected with `reject_value`
+    pub fn clear_rejected_value(&self, field_name: &str) {
+        self.rejected_values.update_value(|rejected| {
+            rejected.remove(field_name);
+        });
+    }
+
+    /// Get the error of a field whose current value was rejected with
+    /// `reject_value`
+    fn rejection(&self, field_name: &str) -> Option<ValidationError> {
+        let (value, error) = self
+            .rejected_values
+            .with_value(|rejected| rejected.get(field_name).cloned())?;
+        let current = self.state.with_untracked(|state| {
+            state
+                .fields
+                .get(field_name)
+                .map(|field| field.value.get_untracked())
+        });
+        (current.as_deref() == Some(value.as_str())).then_some(error)
     }
 
     /// Get the typed form data (after validation)
@@ -209,6 +1602,31 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> Form<T> {
         self.form_data.get_untracked()
     }
 
+    /// Get a signal of the typed form data that stays in sync with the fields
+    ///
+    /// The field values are re-parsed whenever one changes, so derived UI can
+    /// compute from typed values. A field that fails to parse keeps its last
+    /// synced value. Unlike `validate`, this doesn't touch the form's errors.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let data = form.data_signal();
+    /// let total = move || data.with(|order| order.quantity as f64 * order.unit_price);
+    /// ```
+    pub fn data_signal(&self) -> Signal<T> {
+        let form = *self;
+        Signal::derive(move || {
+            form.state.with(|state| {
+                for field in state.fields.values() {
+                    field.value.track();
+                }
+                let mut data = form.form_data.get_untracked();
+                data.sync_from_strings(&state.fields);
+                data
+            })
+        })
+    }
+
     /// Get the internal state signal
     pub(crate) fn state_signal(&self) -> RwSignal<FormState> {
         self.state
@@ -225,7 +1643,47 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> Form<T> {
     ///
     /// The error signals in Field components automatically update when this method
     /// modifies the form state, providing immediate feedback to users.
+    ///
+    /// Fields depending on this one, through `#[field(depends_on = "...")]` or
+    /// `add_dependency`, are revalidated too, if they are touched, dirty or
+    /// already have an error. A confirmation field the user hasn't reached yet
+    /// isn't flagged while they type the field it confirms.
     pub fn validate_field(&self, field_name: &str) {
+        self.validate_single_field(field_name);
+
+        // Cascade one level only, so cyclic dependencies can't recurse forever
+        let mut dependents: Vec<String> = T::field_dependents(field_name)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        self.dependencies.with_value(|dependencies| {
+            if let Some(added) = dependencies.get(field_name) {
+                dependents.extend(added.iter().cloned());
+            }
+        });
+        dependents.sort();
+        dependents.dedup();
+        self.state.with_untracked(|state| {
+            dependents.retain(|dependent| {
+                dependent != field_name
+                    && (state.is_field_touched(dependent)
+                        || state.is_field_dirty(dependent)
+                        || state.errors.contains_key(dependent))
+            });
+        });
+        for dependent in &dependents {
+            self.validate_single_field(dependent);
+        }
+    }
+
+    /// Validate a single field by name, without cascading to its dependents
+    fn validate_single_field(&self, field_name: &str) {
+        // Hidden fields aren't validated, and lose any error they had
+        if self.is_field_hidden(field_name) {
+            self.clear_field_error(field_name);
+            return;
+        }
+
         // First, sync the specific field value to form_data
         let state = self.state.get_untracked();
 
@@ -244,16 +1702,44 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> Form<T> {
 
             // If there was a parse error, set it and return
             if let Some(parse_error) = parse_errors.get(field_name) {
+                let error_mode = self.error_mode();
                 self.state.update(|state| {
                     state
                         .errors
                         .insert(field_name.to_string(), parse_error.clone());
+                    if error_mode == ErrorMode::All {
+                        state
+                            .all_errors
+                            .insert(field_name.to_string(), vec![parse_error.clone()]);
+                    }
                 });
                 return;
             }
 
             // No parse error, run field validation
-            let validation_result = self.form_data.get_untracked().validate_field(field_name);
+            let data = self.form_data.get_untracked();
+            let mut validation_result = data.validate_field(field_name);
+            let mut rejected = false;
+            if validation_result
+                .as_ref()
+                .err()
+                .is_none_or(ValidationError::is_warning)
+            {
+                if let Some(error) = self.rejection(field_name) {
+                    validation_result = Err(error);
+                    rejected = true;
+                }
+            }
+            let all_errors = match (&validation_result, self.error_mode()) {
+                (Err(err), ErrorMode::All) => {
+                    let mut all_errors = data.validate_field_all(field_name);
+                    if rejected {
+                        all_errors.insert(0, err.clone());
+                    }
+                    Some(all_errors)
+                }
+                _ => None,
+            };
 
             // Update the error state based on validation result
             self.state.update(|state| {
@@ -261,9 +1747,13 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> Form<T> {
                     Ok(()) => {
                         // Validation passed, remove any existing error
                         state.errors.remove(field_name);
+                        state.all_errors.remove(field_name);
                     }
                     Err(err) => {
                         // Validation failed, set the error
+                        if let Some(all_errors) = all_errors {
+                            state.all_errors.insert(field_name.to_string(), all_errors);
+                        }
                         state.errors.insert(field_name.to_string(), err);
                     }
                 }
@@ -278,6 +1768,86 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> Default for Fo
     }
 }
 
+/// Parse an `application/x-www-form-urlencoded` string into name and value
+/// pairs, in order
+///
+/// A leading `?` is ignored, so query strings can be passed as they are.
+///
+/// # Example
+/// ```rust,ignore
+/// let pairs = parse_url_encoded("?status=open&min_age=18");
+/// assert_eq!(pairs[0], ("status".to_string(), "open".to_string()));
+/// ```
+pub fn parse_url_encoded(input: &str) -> Vec<(String, String)> {
+    let input = input.strip_prefix('?').unwrap_or(input);
+    form_urlencoded::parse(input.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+/// Join the values of each name into the field's value, keeping the order in
+/// which names first appear
+///
+/// The values of list fields, and repeated values of any field, are escaped
+/// into the list `Vec` fields parse.
+fn join_repeated(
+    pairs: impl IntoIterator<Item = (String, String)>,
+    is_list: impl Fn(&str) -> bool,
+) -> Vec<(String, String)> {
+    let mut grouped: Vec<(String, Vec<String>)> = Vec::new();
+    for (name, value) in pairs {
+        match grouped
+            .iter_mut()
+            .find(|(grouped_name, _)| *grouped_name == name)
+        {
+            Some((_, values)) => values.push(value),
+            None => grouped.push((name, vec![value])),
+        }
+    }
+    grouped
+        .into_iter()
+        .map(|(name, mut values)| {
+            // A single value of another field is kept as it is, e.g. text
+            // with commas
+            let value = if values.len() == 1 && !is_list(&name) {
+                values.remove(0)
+            } else {
+                values.to_field_value()
+            };
+            (name, value)
+        })
+        .collect()
+}
+
+/// Get the form provided by an enclosing `FormComponent`
+///
+/// `Field`, `GetField` and `FieldArray` fall back to this when their `form`
+/// prop is omitted.
+///
+/// # Panics
+///
+/// Panics if there is no `FormComponent` for a `Form<T>` above the caller.
+///
+/// # Example
+/// ```rust,ignore
+/// #[component]
+/// fn SaveButton() -> impl IntoView {
+///     let form = use_form::<ContactForm>();
+///     view! { <button disabled=move || form.is_submitting().get()>"Save"</button> }
+/// }
+/// ```
+pub fn use_form<T>() -> Form<T>
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    use_context::<Form<T>>().unwrap_or_else(|| {
+        panic!(
+            "use_form::<{}>() called outside a FormComponent for that form",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
 /// Form component that provides form state to children via context
 ///
 /// # Example
@@ -300,6 +1870,10 @@ where
     // Provide form context to children
     provide_context(form);
 
+    // Mirror the form's query fields into the URL
+    #[cfg(feature = "router")]
+    super::query::sync_query(form);
+
     // Create derived signals for form values and errors
     let form_values = Signal::derive(move || form.values());
 
@@ -318,6 +1892,9 @@ where
         }
     });
 
+    // Create derived signal for the names of the dirty fields
+    let form_dirty_fields = Signal::derive(move || form.dirty_fields());
+
     // Create derived signal for form touched state
     let form_touched = Signal::derive({
         move || {
@@ -326,11 +1903,22 @@ where
         }
     });
 
+    // Create derived signal for the names of the touched fields
+    let form_touched_fields = Signal::derive(move || form.touched_fields());
+
     // Create derived signal for form valid state
     let form_valid = Signal::derive({
         move || {
             let state = form.state_signal().get();
-            state.errors.is_empty()
+            state.errors.values().all(ValidationError::is_warning)
+        }
+    });
+
+    // Create derived signal for async validation in progress
+    let form_is_validating = Signal::derive({
+        move || {
+            let state = form.state_signal().get();
+            state.is_validating()
         }
     });
 
@@ -339,8 +1927,14 @@ where
         values: form_values,
         errors: form_errors,
         dirty: form_dirty,
+        dirty_fields: form_dirty_fields,
         touched: form_touched,
+        touched_fields: form_touched_fields,
         valid: form_valid,
+        is_validating: form_is_validating,
+        is_submitting: form.is_submitting(),
+        submit_count: form.submit_count(),
+        submit_succeeded: form.submit_succeeded(),
     };
 
     // Pass state to children via the children function
@@ -353,3 +1947,281 @@ impl<T: FormValidation + Default + Clone + Send + Sync + 'static> From<T> for Fo
         Self::from(data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Severity;
+    use std::task::{Context, Poll, Waker};
+
+    /// A form whose `username` warns when it is short
+    #[derive(Clone, Default)]
+    struct Signup {
+        username: String,
+    }
+
+    impl FormValidation for Signup {
+        fn validate_all(&self) -> HashMap<String, ValidationError> {
+            self.validate_field("username")
+                .err()
+                .map(|error| ("username".to_string(), error))
+                .into_iter()
+                .collect()
+        }
+
+        fn validate_field(&self, field_name: &str) -> ValidationResult {
+            if field_name == "username" && self.username.len() < 4 {
+                return Err(ValidationError::new("username", "Username is short")
+                    .with_severity(Severity::Warning));
+            }
+            Ok(())
+        }
+
+        fn field_names() -> Vec<&'static str> {
+            vec!["username"]
+        }
+
+        fn sync_from_strings(
+            &mut self,
+            fields: &HashMap<String, FieldSignal>,
+        ) -> HashMap<String, ValidationError> {
+            if let Some(field) = fields.get("username") {
+                self.username = field.value.get_untracked();
+            }
+            HashMap::new()
+        }
+
+        fn to_strings(&self) -> HashMap<String, String> {
+            HashMap::from([("username".to_string(), self.username.clone())])
+        }
+    }
+
+    /// Pending on its first poll, like a request waiting on the server
+    struct Wait(bool);
+
+    impl Future for Wait {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                Poll::Pending
+            }
+        }
+    }
+
+    /// A form for `username` with an async validator rejecting taken names
+    fn signup_form(username: &str) -> Form<Signup> {
+        let data = Signup {
+            username: username.to_string(),
+        };
+        Form::from(data).with_async_validator("username", |data: Signup| async move {
+            Wait(false).await;
+            if matches!(data.username.as_str(), "jed" | "admin") {
+                Err(ValidationError::new("username", "Username is taken"))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn poll<F: Future>(future: Pin<&mut F>) -> Poll<F::Output> {
+        future.poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    fn username_error(form: &Form<Signup>) -> Option<ValidationError> {
+        form.state_signal()
+            .with_untracked(|state| state.errors.get("username").cloned())
+    }
+
+    #[test]
+    fn async_error_replaces_warning() {
+        let form = signup_form("jed");
+        let mut validation = std::pin::pin!(form.validate_async());
+        assert!(poll(validation.as_mut()).is_pending());
+        assert_eq!(poll(validation.as_mut()), Poll::Ready(false));
+
+        let error = username_error(&form).unwrap();
+        assert!(!error.is_warning());
+        assert_eq!(error.message(), "Username is taken");
+    }
+
+    #[test]
+    fn async_result_dropped_after_edit() {
+        let form = signup_form("admin");
+        let mut validation = std::pin::pin!(form.validate_async());
+        assert!(poll(validation.as_mut()).is_pending());
+        form.set_value("username", "admin2");
+        assert_eq!(poll(validation.as_mut()), Poll::Ready(false));
+
+        assert!(username_error(&form).is_none());
+        assert!(!form.state_signal().with_untracked(FormState::is_validating));
+    }
+
+    #[test]
+    fn async_result_dropped_after_reset() {
+        let form = signup_form("jed");
+        let mut validation = std::pin::pin!(form.validate_async());
+        assert!(poll(validation.as_mut()).is_pending());
+        form.reset();
+        assert_eq!(poll(validation.as_mut()), Poll::Ready(false));
+
+        assert!(username_error(&form).is_none());
+        assert!(!form.state_signal().with_untracked(FormState::is_validating));
+    }
+
+    /// A form whose `confirm` must match its `password`
+    #[derive(Clone, Default)]
+    struct Account {
+        password: String,
+        confirm: String,
+    }
+
+    impl FormValidation for Account {
+        fn validate_all(&self) -> HashMap<String, ValidationError> {
+            self.validate_field("confirm")
+                .err()
+                .map(|error| ("confirm".to_string(), error))
+                .into_iter()
+                .collect()
+        }
+
+        fn validate_field(&self, field_name: &str) -> ValidationResult {
+            if field_name == "confirm" && self.confirm != self.password {
+                return Err(ValidationError::new("confirm", "Passwords must match"));
+            }
+            Ok(())
+        }
+
+        fn field_names() -> Vec<&'static str> {
+            vec!["password", "confirm"]
+        }
+
+        fn sync_from_strings(
+            &mut self,
+            fields: &HashMap<String, FieldSignal>,
+        ) -> HashMap<String, ValidationError> {
+            if let Some(field) = fields.get("password") {
+                self.password = field.value.get_untracked();
+            }
+            if let Some(field) = fields.get("confirm") {
+                self.confirm = field.value.get_untracked();
+            }
+            HashMap::new()
+        }
+
+        fn to_strings(&self) -> HashMap<String, String> {
+            HashMap::from([
+                ("password".to_string(), self.password.clone()),
+                ("confirm".to_string(), self.confirm.clone()),
+            ])
+        }
+    }
+
+    fn confirm_error(form: &Form<Account>) -> Option<ValidationError> {
+        form.state_signal()
+            .with_untracked(|state| state.errors.get("confirm").cloned())
+    }
+
+    #[test]
+    fn dependents_revalidate_once_reached() {
+        let form = Form::from(Account::default()).add_dependency("password", "confirm");
+
+        // The confirmation hasn't been reached, so typing a password leaves it be
+        form.set_value("password", "secret");
+        form.validate_field("password");
+        assert!(confirm_error(&form).is_none());
+
+        form.state_signal().update(|state| {
+            state.touched.insert("confirm".to_string(), true);
+        });
+        form.validate_field("password");
+        assert!(confirm_error(&form).is_some());
+
+        // A clean, untouched dependent is still revalidated to clear its error
+        form.state_signal().update(|state| {
+            state.touched.clear();
+        });
+        form.set_value("password", "");
+        form.validate_field("password");
+        assert!(confirm_error(&form).is_none());
+    }
+
+    #[test]
+    fn visibility_rules_see_unregistered_fields() {
+        let form =
+            Form::<Signup>::new().show_when("nickname", |values| values["username"].is_empty());
+        assert!(form
+            .state_signal()
+            .with_untracked(|state| state.fields.is_empty()));
+
+        assert!(form.validate());
+        assert!(form.is_field_visible("nickname"));
+        form.set_value("username", "jedsaw");
+        assert!(!form.is_field_visible("nickname"));
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_url_encoded_decodes_pairs_in_order() {
+        assert_eq!(
+            parse_url_encoded("?status=open&name=Jed+Saw&email=jed%40email.com"),
+            pairs(&[
+                ("status", "open"),
+                ("name", "Jed Saw"),
+                ("email", "jed@email.com"),
+            ])
+        );
+        assert_eq!(
+            parse_url_encoded("tags=a&tags=b%2Cc&empty=&flag"),
+            pairs(&[("tags", "a"), ("tags", "b,c"), ("empty", ""), ("flag", "")])
+        );
+        assert_eq!(parse_url_encoded(""), pairs(&[]));
+        assert_eq!(parse_url_encoded("?"), pairs(&[]));
+    }
+
+    #[test]
+    fn join_repeated_escapes_list_values() {
+        let joined = join_repeated(
+            pairs(&[("cities", "Kuala Lumpur, MY"), ("note", "a, b")]),
+            |name| name == "cities",
+        );
+        assert_eq!(
+            joined,
+            pairs(&[("cities", r"Kuala Lumpur\, MY"), ("note", "a, b")])
+        );
+        assert_eq!(
+            Vec::<String>::from_field_value("cities", &joined[0].1).unwrap(),
+            ["Kuala Lumpur, MY"]
+        );
+    }
+
+    #[test]
+    fn join_repeated_joins_repeated_values() {
+        let joined = join_repeated(
+            pairs(&[
+                ("tags", "a,b"),
+                ("name", "Jed"),
+                ("tags", r"c\d"),
+                ("tags", " e"),
+            ]),
+            |_| false,
+        );
+        assert_eq!(
+            joined,
+            pairs(&[("tags", r"a\,b,c\\d,\ e"), ("name", "Jed")])
+        );
+        assert_eq!(
+            Vec::<String>::from_field_value("tags", &joined[0].1).unwrap(),
+            ["a,b", r"c\d", " e"]
+        );
+    }
+}
@@ -1,25 +1,135 @@
 use leptos::prelude::*;
 
-use crate::{validation::FormValidation, FieldState};
+use crate::{
+    form::BindOn,
+    validation::{FormValidation, TextBounds},
+    FieldState,
+};
 
 /// Input component for form fields.
 ///
 /// This component binds the input value and sets up mark touched
-/// on blur to the FieldState.
+/// on blur to the FieldState. The value is updated on `input` or `change`
+/// events depending on the field's `bind_on` setting, which can be
+/// overridden per input.
+///
+/// It sets `aria-required` for required fields, and while the field has a
+/// visible error, `aria-invalid` and `aria-describedby` pointing at the
+/// element with the field's `error_id`.
+///
+/// The browser's constraint attributes come from the field's validators:
+/// `required`, `minlength`, `maxlength` and `pattern` from `required`, `length`
+/// and `pattern`, and `min` and `max` from `range` for number inputs. Browsers
+/// then enforce the same rules before any WebAssembly has loaded. Add
+/// `novalidate` to the `<form>` to leave reporting errors to the form alone.
+///
+/// A field with a `FieldFormat`, e.g. from `#[field(mask = "##-###")]`, is
+/// shown formatted and stored as typed without the format's characters. Its
+/// `minlength`, `maxlength` and `pattern` attributes are left out, as they
+/// would check the formatted text.
+///
+/// The common input attributes have props of their own. Any other attribute
+/// is passed through to the `<input>` with the `attr:` prefix, e.g.
+/// `attr:inputmode="numeric"`.
 ///
 /// # Example
 /// ```rust,ignore
 /// view! {
 ///     <Field form=form name="email" let:field_state>
-///         <Input state=field_state class="input-class" />
+///         <Input
+///             state=field_state
+///             id="email"
+///             input_type="email"
+///             placeholder="jed@inspire.my"
+///             autocomplete="email"
+///             class="input-class"
+///         />
+///     </Field>
+///     <Field form=form name="bio" let:field_state>
+///         <Input state=field_state bind_on=BindOn::Change />
 ///     </Field>
 /// }
 /// ```
 #[component]
-pub fn Input<T>(state: FieldState<T>, #[prop(into, optional)] class: &'static str) -> impl IntoView
+pub fn Input<T>(
+    state: FieldState<T>,
+    #[prop(into, optional)] class: &'static str,
+    #[prop(optional, into)] bind_on: Option<BindOn>,
+    /// The input's `type`, e.g. `"email"` (defaults to a text input)
+    #[prop(optional)]
+    input_type: Option<&'static str>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(optional)] placeholder: Option<&'static str>,
+    #[prop(optional)] autocomplete: Option<&'static str>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+) -> impl IntoView
 where
     T: FormValidation + Default + Clone + Send + Sync + 'static,
 {
     let value = state.value();
-    view! { <input bind:value=value class=class on:blur=move |_| state.mark_touched() /> }
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bind_on = bind_on.unwrap_or(state.bind_on);
+    // Length limits apply to the stored value, not the formatted text
+    let text = if state.format.is_some() {
+        TextBounds::default()
+    } else {
+        T::text_bounds(&state.field_name)
+    };
+    let node_ref = NodeRef::<leptos::html::Input>::new();
+    let set_value = Callback::new({
+        let state = state.clone();
+        move |typed: String| {
+            value.set(state.parse_input(&typed));
+            // Show the formatted value, even when typing a character the format
+            // drops leaves the stored value as it was
+            if state.format.is_some() {
+                if let Some(element) = node_ref.get_untracked() {
+                    let shown = state.format_value(&value.get_untracked());
+                    if element.value() != shown {
+                        element.set_value(&shown);
+                    }
+                }
+            }
+        }
+    });
+    let shown = {
+        let state = state.clone();
+        move || state.format_value(&value.get())
+    };
+    let number = T::number_bounds(&state.field_name);
+    view! {
+        <input
+            type=input_type
+            id=id
+            placeholder=placeholder
+            autocomplete=autocomplete
+            disabled=move || disabled.get().unwrap_or(false)
+            required=state.is_required()
+            minlength=text.min_length.map(|min| min.to_string())
+            maxlength=text.max_length.map(|max| max.to_string())
+            pattern=text.pattern
+            min=number.min.map(|min| min.to_string())
+            max=number.max.map(|max| max.to_string())
+            node_ref=node_ref
+            prop:value=shown
+            on:input=move |ev| {
+                if bind_on == BindOn::Input {
+                    set_value.run(event_target_value(&ev));
+                }
+            }
+            on:change=move |ev| {
+                if bind_on == BindOn::Change {
+                    set_value.run(event_target_value(&ev));
+                }
+            }
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
 }
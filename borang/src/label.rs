@@ -0,0 +1,56 @@
+use leptos::prelude::*;
+
+use crate::{validation::FormValidation, FieldState};
+
+/// Label component for form fields.
+///
+/// This component renders the field's label from `#[field(label = "...")]`,
+/// or the field name if it has none. The label points at the element whose
+/// id is the field name, which every control uses as its id unless given an
+/// `id` prop.
+///
+/// Fields with a `required` validator get a marker after the label, `*` by
+/// default. It is hidden from screen readers, which learn the field is
+/// required from the input's `aria-required`. Pass `required_marker=""` to
+/// leave it out.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="email" let:field_state>
+///         <Label state=field_state.clone() class="label-class" marker_class="required" />
+///         <Input state=field_state id="email" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn Label<T>(
+    state: FieldState<T>,
+    #[prop(into, optional)] class: &'static str,
+    /// Text shown after the label of a required field (defaults to `*`)
+    #[prop(optional)]
+    required_marker: Option<&'static str>,
+    /// Class of the element holding the required marker
+    #[prop(into, optional)]
+    marker_class: &'static str,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let marker = required_marker.unwrap_or("*");
+    let show_marker = state.is_required() && !marker.is_empty();
+    view! {
+        <label for=state.field_name.clone() class=class>
+            {state.label().to_string()}
+            {show_marker
+                .then(|| {
+                    view! {
+                        " "
+                        <span class=marker_class aria-hidden="true">
+                            {marker}
+                        </span>
+                    }
+                })}
+        </label>
+    }
+}
@@ -1,18 +1,87 @@
 //! # Borang API
 
+pub mod auto_form;
+pub mod checkbox;
+pub mod checkbox_group;
+pub mod color_input;
+pub mod combobox;
+pub mod currency_input;
+pub mod date_input;
+pub mod dynamic;
+pub mod error_announcer;
+pub mod error_message;
+pub mod error_summary;
 pub mod field;
+pub mod field_array;
+pub mod fieldset;
+pub mod file_input;
 pub mod form;
 pub mod input;
+pub mod label;
 pub mod macros;
+pub mod multi_select;
+#[doc(hidden)]
+pub mod nested;
+pub mod number_input;
+pub mod password_input;
+pub mod phone_input;
+#[cfg(feature = "router")]
+mod query;
+pub mod range_slider;
+pub mod rating_input;
+pub mod rules;
 pub mod select;
+pub mod show_field;
+pub mod switch;
+pub mod tags_input;
+pub mod textarea;
 pub mod validation;
 
 // Re-export core types
+pub use auto_form::AutoForm;
+pub use checkbox::Checkbox;
+pub use checkbox_group::CheckboxGroup;
+pub use color_input::ColorInput;
+pub use combobox::Combobox;
+pub use currency_input::CurrencyInput;
+pub use date_input::DateInput;
+pub use dynamic::{DynamicData, DynamicValue, FieldType, FormSchema, SchemaField, SchemaValidator};
+pub use error_announcer::ErrorAnnouncer;
+pub use error_message::ErrorMessage;
+pub use error_summary::ErrorSummary;
 pub use field::{Field, FieldState, GetField};
-pub use form::{Form, FormComponent, FormComponentState, FormState};
+pub use field_array::{FieldArray, FieldArrayItem, FieldArrayState};
+pub use fieldset::{Fieldset, FieldsetState};
+pub use file_input::FileInput;
+pub use form::{
+    parse_url_encoded, use_form, BindOn, ErrorMode, Form, FormComponent, FormComponentState,
+    FormSnapshot, FormState, ResetMode, ShowErrors, ValidateMode,
+};
 pub use input::Input;
+pub use label::Label;
 pub use macros::FormValidation as Validation;
+pub use macros::FromFieldValue;
+pub use multi_select::MultiSelect;
+pub use number_input::NumberInput;
+pub use password_input::PasswordInput;
+pub use phone_input::PhoneInput;
+pub use range_slider::RangeSlider;
+pub use rating_input::RatingInput;
 pub use select::Select;
+pub use show_field::ShowField;
+pub use switch::Switch;
+pub use tags_input::TagsInput;
+pub use textarea::Textarea;
 pub use validation::{
-    ErrorKind, FieldSignal, FormValidation, FromFieldValue, ValidationError, ValidationResult,
+    EmailMode, ErrorKind, FieldFormat, FieldMeta, FieldOptions, FieldSignal, FileInfo,
+    FormValidation, FromFieldValue, HexColor, NumberBounds, PasswordRequirement, RangeBound,
+    Severity, SignConstraint, TextBounds, ValidationError, ValidationResult, ValidatorMeta,
+    FORM_ERROR_KEY,
 };
+
+/// Dependencies of the code generated by the derive macros, so crates using
+/// them don't need their own
+#[doc(hidden)]
+pub mod __private {
+    pub use regex::Regex;
+}
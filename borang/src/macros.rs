@@ -21,3 +21,24 @@
 /// }
 /// ```
 pub use borang_macros::FormValidation;
+
+/// Re-export of the FromFieldValue derive macro from borang-macros.
+///
+/// This macro implements `FromFieldValue` for fieldless enums, using each
+/// variant's name or its `#[field_value = "..."]` string. It also implements
+/// `FieldOptions`, labelling variants by name or `#[field_label = "..."]`.
+///
+/// # Example
+///
+/// ```ignore
+/// use borang::FromFieldValue;
+///
+/// #[derive(FromFieldValue, Default, Clone)]
+/// enum Country {
+///     #[field_value = "my"]
+///     Malaysia,
+///     #[default]
+///     Other,
+/// }
+/// ```
+pub use borang_macros::FromFieldValue;
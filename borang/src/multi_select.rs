@@ -0,0 +1,97 @@
+use leptos::prelude::*;
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{HtmlOptionElement, HtmlSelectElement};
+
+use crate::{
+    validation::{FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// MultiSelect component for `Vec` form fields.
+///
+/// This component renders a `<select multiple>` and keeps the field's value
+/// as the comma-separated list of selected option values that `Vec` fields
+/// parse, with commas in option values escaped. It sets up mark touched on
+/// blur and sets the same ARIA attributes as `Input`. Options are given as
+/// children, or as `(value, label)` pairs with `options` like `Select`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="languages" let:field_state>
+///         <MultiSelect state=field_state class="select-class">
+///             <option value="en">"English"</option>
+///             <option value="ms">"Malay"</option>
+///             <option value="zh">"Chinese"</option>
+///         </MultiSelect>
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn MultiSelect<T>(
+    state: FieldState<T>,
+    /// Id of the `<select>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    /// Options to render after the children, as `(value, label)` pairs
+    #[prop(into, optional)]
+    options: Vec<(String, String)>,
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let node_ref = NodeRef::<leptos::html::Select>::new();
+
+    // Select the options listed in the field value
+    let name = state.field_name.clone();
+    Effect::new(move |_| {
+        let selected = Vec::<String>::from_field_value(&name, &value.get()).unwrap_or_default();
+        if let Some(select) = node_ref.get() {
+            for option in option_elements(&select) {
+                option.set_selected(selected.contains(&option.value()));
+            }
+        }
+    });
+
+    let on_change = move |ev: leptos::ev::Event| {
+        let select = event_target::<HtmlSelectElement>(&ev);
+        let selected: Vec<String> = option_elements(&select)
+            .into_iter()
+            .filter(HtmlOptionElement::selected)
+            .map(|option| option.value())
+            .collect();
+        value.set(selected.to_field_value());
+    };
+
+    view! {
+        <select
+            id=id
+            node_ref=node_ref
+            multiple
+            class=class
+            on:change=on_change
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        >
+            {children.map(|children| children())}
+            {options
+                .into_iter()
+                .map(|(value, label)| view! { <option value=value>{label}</option> })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// Get the `<option>` elements of a select
+fn option_elements(select: &HtmlSelectElement) -> Vec<HtmlOptionElement> {
+    (0..select.length())
+        .filter_map(|index| select.item(index)?.dyn_into::<HtmlOptionElement>().ok())
+        .collect()
+}
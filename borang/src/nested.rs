@@ -0,0 +1,109 @@
+//! Helpers called by the code `#[derive(FormValidation)]` generates for
+//! `#[field(nested)]` fields, whose child fields are addressed with dotted
+//! paths like `address.street`.
+
+use std::collections::HashMap;
+
+use super::validation::{
+    FieldFormat, FieldSignal, FormValidation, NumberBounds, TextBounds, ValidationError,
+    ValidationResult,
+};
+
+/// Get the fields starting with `prefix`, keyed without it
+pub(crate) fn sub_fields(
+    fields: &HashMap<String, FieldSignal>,
+    prefix: &str,
+) -> HashMap<String, FieldSignal> {
+    fields
+        .iter()
+        .filter_map(|(key, field)| Some((key.strip_prefix(prefix)?.to_string(), field.clone())))
+        .collect()
+}
+
+/// Get the child field name of a dotted path, e.g. `street` for `address.street`
+fn child_name<'a>(path: &str, name: &'a str) -> Option<&'a str> {
+    name.strip_prefix(path)?.strip_prefix('.')
+}
+
+pub fn sync_nested<C: FormValidation>(
+    child: &mut C,
+    path: &str,
+    fields: &HashMap<String, FieldSignal>,
+) -> HashMap<String, ValidationError> {
+    let prefix = format!("{}.", path);
+    let child_fields = sub_fields(fields, &prefix);
+    if child_fields.is_empty() {
+        return HashMap::new();
+    }
+    child
+        .sync_from_strings(&child_fields)
+        .into_iter()
+        .map(|(field_name, error)| (format!("{}{}", prefix, field_name), error))
+        .collect()
+}
+
+pub fn nested_to_strings<C: FormValidation>(
+    child: &C,
+    path: &str,
+    map: &mut HashMap<String, String>,
+) {
+    for (field_name, value) in child.to_strings() {
+        map.insert(format!("{}.{}", path, field_name), value);
+    }
+}
+
+pub fn validate_nested<C: FormValidation>(
+    child: &C,
+    path: &str,
+    errors: &mut HashMap<String, ValidationError>,
+) {
+    for (field_name, error) in child.validate_all() {
+        errors.insert(format!("{}.{}", path, field_name), error);
+    }
+}
+
+pub fn validate_nested_field<C: FormValidation>(
+    child: &C,
+    path: &str,
+    name: &str,
+) -> ValidationResult {
+    match child_name(path, name) {
+        Some(field_name) => child.validate_field(field_name),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_nested_field_all<C: FormValidation>(
+    child: &C,
+    path: &str,
+    name: &str,
+) -> Vec<ValidationError> {
+    match child_name(path, name) {
+        Some(field_name) => child.validate_field_all(field_name),
+        None => Vec::new(),
+    }
+}
+
+pub fn nested_label<C: FormValidation>(path: &str, name: &str) -> Option<&'static str> {
+    C::field_label(child_name(path, name)?)
+}
+
+pub fn nested_required<C: FormValidation>(path: &str, name: &str) -> bool {
+    child_name(path, name).is_some_and(C::is_field_required)
+}
+
+pub fn nested_number_bounds<C: FormValidation>(path: &str, name: &str) -> NumberBounds {
+    child_name(path, name)
+        .map(C::number_bounds)
+        .unwrap_or_default()
+}
+
+pub fn nested_text_bounds<C: FormValidation>(path: &str, name: &str) -> TextBounds {
+    child_name(path, name)
+        .map(C::text_bounds)
+        .unwrap_or_default()
+}
+
+pub fn nested_format<C: FormValidation>(path: &str, name: &str) -> Option<FieldFormat> {
+    C::field_format(child_name(path, name)?)
+}
@@ -0,0 +1,102 @@
+use leptos::prelude::*;
+
+use crate::{form::BindOn, validation::FormValidation, FieldState};
+
+/// NumberInput component for numeric form fields.
+///
+/// This component renders an `<input type="number">` bound like `Input`.
+/// Its `min`, `max` and `step` attributes default to the field's `range` and
+/// `multiple_of` validators, and can be set explicitly instead. Fields with a
+/// `required` validator get the `required` attribute.
+///
+/// While the user types something the browser can't read as a number yet,
+/// such as `-` or `1e`, the browser reports an empty value. Unlike `Input`,
+/// this component doesn't write that empty value back, so the partial text
+/// stays in the input.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="age" let:field_state>
+///         <NumberInput state=field_state class="input-class" />
+///     </Field>
+///     <Field form=form name="price" let:field_state>
+///         <NumberInput state=field_state step=0.01 />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn NumberInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    #[prop(optional, into)] bind_on: Option<BindOn>,
+    /// Smallest allowed value (defaults to the field's `range` minimum)
+    #[prop(optional, into)]
+    min: Option<f64>,
+    /// Largest allowed value (defaults to the field's `range` maximum)
+    #[prop(optional, into)]
+    max: Option<f64>,
+    /// Step between values (defaults to the field's `multiple_of`)
+    #[prop(optional, into)]
+    step: Option<f64>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bind_on = bind_on.unwrap_or(state.bind_on);
+    let bounds = T::number_bounds(&state.field_name);
+    let min = min
+        .map(|min| min.to_string())
+        .or_else(|| bounds.min.map(|min| min.to_string()));
+    let max = max
+        .map(|max| max.to_string())
+        .or_else(|| bounds.max.map(|max| max.to_string()));
+    let step = step
+        .map(|step| step.to_string())
+        .or_else(|| bounds.step.map(|step| step.to_string()));
+
+    // Write the field value to the input, unless it only differs because the
+    // input holds text that isn't a number yet
+    let node_ref = NodeRef::<leptos::html::Input>::new();
+    Effect::new(move |_| {
+        let value = value.get();
+        if let Some(input) = node_ref.get() {
+            if input.value() != value {
+                input.set_value(&value);
+            }
+        }
+    });
+
+    view! {
+        <input
+            id=id
+            node_ref=node_ref
+            type="number"
+            required=state.is_required()
+            min=min
+            max=max
+            step=step
+            on:input=move |ev| {
+                if bind_on == BindOn::Input {
+                    value.set(event_target_value(&ev));
+                }
+            }
+            on:change=move |ev| {
+                if bind_on == BindOn::Change {
+                    value.set(event_target_value(&ev));
+                }
+            }
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
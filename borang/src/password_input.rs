@@ -0,0 +1,159 @@
+use leptos::prelude::*;
+
+use crate::{
+    form::BindOn,
+    validation::{ErrorKind, FormValidation, PasswordRequirement},
+    FieldState,
+};
+
+/// PasswordInput component for password form fields.
+///
+/// This component renders a password input bound like `Input`, followed by a
+/// button showing or hiding the password. The input's id defaults to the
+/// field name, so `Label` points at it, and attributes set on the component go to the
+/// wrapping `<div>`.
+///
+/// With `show_strength`, a `<meter>` scores the password against the field's
+/// `password(min_len, min_classes)` policy: a point for the minimum length and
+/// one for each required character class, by what the validator reports as
+/// missing. A valid password gets the full score, and a password failing
+/// another validator scores 0. Without a `password` validator, the meter only
+/// shows whether the field is valid. The score follows the field's validation,
+/// so it updates as the field is validated.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="password" let:field_state>
+///         <Label state=field_state.clone() />
+///         <PasswordInput state=field_state input_class="input-class" show_strength=true />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn PasswordInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// Class for the wrapping `<div>`
+    #[prop(into, optional)]
+    class: &'static str,
+    /// Class for the `<input>`
+    #[prop(into, optional)]
+    input_class: &'static str,
+    /// Class for the show/hide button
+    #[prop(into, optional)]
+    toggle_class: &'static str,
+    /// Text of the button while the password is hidden (defaults to "Show")
+    #[prop(into, optional)]
+    show_label: Option<&'static str>,
+    /// Text of the button while the password is shown (defaults to "Hide")
+    #[prop(into, optional)]
+    hide_label: Option<&'static str>,
+    /// Show a strength meter below the input
+    #[prop(optional)]
+    show_strength: bool,
+    #[prop(optional, into)] bind_on: Option<BindOn>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bind_on = bind_on.unwrap_or(state.bind_on);
+    let show_label = show_label.unwrap_or("Show");
+    let hide_label = hide_label.unwrap_or("Hide");
+    let visible = RwSignal::new(false);
+    // The minimum length counts as one requirement, next to the classes
+    let min_classes = T::text_bounds(&state.field_name)
+        .password
+        .map(|(_, min_classes)| min_classes);
+    let requirements = min_classes.map_or(1, |min_classes| min_classes + 1);
+
+    // Score the password by the requirements its validator reports as missing
+    let strength = Signal::derive(move || {
+        if value.get().is_empty() {
+            return 0;
+        }
+        let error = state.err.get();
+        if error.is_none() {
+            return requirements;
+        }
+        let Some(min_classes) = min_classes else {
+            return 0;
+        };
+        // With `ErrorMode::All` the password error may not be the first one
+        let missing = error
+            .into_iter()
+            .chain(state.all_errors.get())
+            .find_map(|error| match error.kind() {
+                ErrorKind::WeakPassword { missing, .. } => Some(missing.clone()),
+                _ => None,
+            });
+        let Some(missing) = missing else {
+            return 0;
+        };
+        let length = !missing
+            .iter()
+            .any(|req| matches!(req, PasswordRequirement::MinLength(_)));
+        // Missing classes are only listed when too few are used, and then
+        // every unused one is
+        let missing_classes = missing
+            .iter()
+            .filter(|req| !matches!(req, PasswordRequirement::MinLength(_)))
+            .count();
+        let classes = if missing_classes == 0 {
+            min_classes
+        } else {
+            (4 - missing_classes).min(min_classes)
+        };
+        usize::from(length) + classes
+    });
+
+    view! {
+        <div class=class>
+            <input
+                id=id.clone()
+                type=move || if visible.get() { "text" } else { "password" }
+                prop:value=move || value.get()
+                on:input=move |ev| {
+                    if bind_on == BindOn::Input {
+                        value.set(event_target_value(&ev));
+                    }
+                }
+                on:change=move |ev| {
+                    if bind_on == BindOn::Change {
+                        value.set(event_target_value(&ev));
+                    }
+                }
+                class=input_class
+                aria-required=state.is_required().then_some("true")
+                aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+                aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+                on:blur=move |_| state.mark_touched()
+            />
+            <button
+                type="button"
+                class=toggle_class
+                aria-controls=id
+                aria-pressed=move || visible.get().to_string()
+                on:click=move |_| visible.update(|visible| *visible = !*visible)
+            >
+                {move || if visible.get() { hide_label } else { show_label }}
+            </button>
+            {show_strength
+                .then(|| {
+                    view! {
+                        <meter
+                            min="0"
+                            max=requirements.to_string()
+                            value=move || strength.get().to_string()
+                            aria-label="Password strength"
+                        />
+                    }
+                })}
+        </div>
+    }
+}
@@ -0,0 +1,191 @@
+use leptos::prelude::*;
+
+use crate::{validation::FormValidation, FieldState};
+
+/// PhoneInput component for phone number form fields.
+///
+/// This component renders a `<select>` of country calling codes next to a
+/// `<input type="tel">` for the national number, and writes both into the
+/// field as one E.164 number, e.g. `+60123456789`. Spaces, dashes, dots and
+/// parentheses in the typed number are dropped, as is a leading trunk `0`, so
+/// `012-345 6789` becomes `123456789`. Other characters are kept for the
+/// field's validators to reject. The field is empty while the number is.
+///
+/// `countries` lists the `(calling code, label)` pairs to choose from, with
+/// codes written without the `+`. A field value set from outside is split on
+/// the longest matching code.
+///
+/// Pair it with a `pattern` validator to check the number, e.g.
+/// `#[validator(pattern = r"^\+[1-9]\d{6,14}$")]`.
+///
+/// # Example
+/// ```rust,ignore
+/// let countries = vec![
+///     ("60".to_string(), "Malaysia (+60)".to_string()),
+///     ("65".to_string(), "Singapore (+65)".to_string()),
+/// ];
+/// view! {
+///     <Field form=form name="phone" let:field_state>
+///         <Label state=field_state.clone() />
+///         <PhoneInput state=field_state countries=countries default_country="60" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn PhoneInput<T>(
+    state: FieldState<T>,
+    /// Id of the number `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// Calling codes to choose from, as `(code, label)` pairs
+    countries: Vec<(String, String)>,
+    /// Calling code chosen when the field is empty (defaults to the first
+    /// country)
+    #[prop(optional)]
+    default_country: Option<&'static str>,
+    /// Class of the element wrapping the select and the input
+    #[prop(into, optional)]
+    class: &'static str,
+    #[prop(into, optional)] select_class: &'static str,
+    #[prop(into, optional)] input_class: &'static str,
+    #[prop(optional)] placeholder: Option<&'static str>,
+    /// Accessible label of the country select (defaults to "Country code")
+    #[prop(optional)]
+    country_label: Option<&'static str>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let default_code = default_country
+        .map(str::to_string)
+        .or_else(|| countries.first().map(|(code, _)| code.clone()))
+        .unwrap_or_default();
+    let codes: Vec<String> = countries.iter().map(|(code, _)| code.clone()).collect();
+    let split = move |phone: &str| split_phone(phone, &codes, &default_code);
+
+    let (initial_code, initial_number) = split(&value.get_untracked());
+    let code = RwSignal::new(initial_code);
+    let number = RwSignal::new(initial_number);
+
+    // Split numbers set from outside, e.g. on reset
+    Effect::new(move |_| {
+        let phone = value.get();
+        if phone != join_phone(&code.get_untracked(), &number.get_untracked()) {
+            let (new_code, new_number) = split(&phone);
+            code.set(new_code);
+            number.set(new_number);
+        }
+    });
+
+    let options = countries
+        .into_iter()
+        .map(|(code, label)| view! { <option value=code>{label}</option> })
+        .collect_view();
+
+    view! {
+        <div class=class>
+            <select
+                aria-label=country_label.unwrap_or("Country code")
+                bind:value=code
+                on:change=move |ev| {
+                    value.set(join_phone(&event_target_value(&ev), &number.get_untracked()));
+                }
+                class=select_class
+            >
+                {options}
+            </select>
+            <input
+                type="tel"
+                id=id
+                autocomplete="tel-national"
+                placeholder=placeholder
+                prop:value=move || number.get()
+                on:input=move |ev| {
+                    let typed = event_target_value(&ev);
+                    value.set(join_phone(&code.get_untracked(), &typed));
+                    number.set(typed);
+                }
+                class=input_class
+                aria-required=state.is_required().then_some("true")
+                aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+                aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+                on:blur=move |_| state.mark_touched()
+            />
+        </div>
+    }
+}
+
+/// Join a calling code and a typed national number into an E.164 number
+fn join_phone(code: &str, number: &str) -> String {
+    let digits: String = number
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect();
+    let digits = digits.strip_prefix('0').unwrap_or(&digits);
+    if digits.is_empty() {
+        String::new()
+    } else {
+        format!("+{}{}", code, digits)
+    }
+}
+
+/// Split an E.164 number into the longest matching calling code and the
+/// national number
+fn split_phone(phone: &str, codes: &[String], default_code: &str) -> (String, String) {
+    let code = phone.strip_prefix('+').and_then(|digits| {
+        codes
+            .iter()
+            .filter(|code| digits.starts_with(code.as_str()))
+            .max_by_key(|code| code.len())
+            .map(|code| (code.clone(), digits[code.len()..].to_string()))
+    });
+    code.unwrap_or_else(|| (default_code.to_string(), phone.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_phone_drops_separators_and_trunk_prefix() {
+        assert_eq!(join_phone("60", "012-345 6789"), "+60123456789");
+        assert_eq!(join_phone("1", "(415) 555.0100"), "+14155550100");
+        assert_eq!(join_phone("60", "0"), "");
+        assert_eq!(join_phone("60", " "), "");
+    }
+
+    #[test]
+    fn split_phone_takes_longest_code() {
+        let codes = ["1", "1242", "60"].map(String::from);
+        assert_eq!(
+            split_phone("+12423571234", &codes, "60"),
+            ("1242".to_string(), "3571234".to_string())
+        );
+        assert_eq!(
+            split_phone("+14155550100", &codes, "60"),
+            ("1".to_string(), "4155550100".to_string())
+        );
+        // Numbers without a known code keep the default code
+        assert_eq!(
+            split_phone("+44201234567", &codes, "60"),
+            ("60".to_string(), "+44201234567".to_string())
+        );
+        assert_eq!(
+            split_phone("0123456789", &codes, "60"),
+            ("60".to_string(), "0123456789".to_string())
+        );
+    }
+
+    #[test]
+    fn split_phone_reverses_join_phone() {
+        let codes = ["1", "60"].map(String::from);
+        let phone = join_phone("60", "012-345 6789");
+        assert_eq!(
+            split_phone(&phone, &codes, "1"),
+            ("60".to_string(), "123456789".to_string())
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Mirrors form fields into the URL query string, for forms created with
+//! `Form::with_query_sync`
+
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_navigate};
+use leptos_router::NavigateOptions;
+
+use super::form::{parse_url_encoded, Form};
+use super::validation::FormValidation;
+
+/// Initialize the form's query fields from the URL, then keep the URL's query
+/// string in step with their values
+///
+/// Empty fields are left out of the query string, and query parameters that
+/// aren't form fields are kept. Must be called under a leptos_router `Router`.
+pub(crate) fn sync_query<T>(form: Form<T>)
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let fields = form.query_fields();
+    if fields.is_empty() {
+        return;
+    }
+
+    let location = use_location();
+    let navigate = use_navigate();
+
+    // Initialize the fields from the query string on load
+    let params = parse_url_encoded(&location.search.get_untracked());
+    form.set_values(params.into_iter().filter(|(name, _)| fields.contains(name)));
+
+    Effect::new(move |_| {
+        // Track every query field, so the URL updates as they change
+        let values: Vec<_> = fields
+            .iter()
+            .map(|name| (name.clone(), form.field_value(name).get()))
+            .filter(|(_, value)| !value.is_empty())
+            .collect();
+
+        let search = location.search.get_untracked();
+        let mut params: Vec<_> = parse_url_encoded(&search)
+            .into_iter()
+            .filter(|(name, _)| !fields.contains(name))
+            .collect();
+        params.extend(values);
+
+        let query = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&params)
+            .finish();
+        if query == search.strip_prefix('?').unwrap_or(&search) {
+            return;
+        }
+
+        let pathname = location.pathname.get_untracked();
+        let url = if query.is_empty() {
+            pathname
+        } else {
+            format!("{}?{}", pathname, query)
+        };
+        navigate(
+            &url,
+            NavigateOptions {
+                replace: true,
+                scroll: false,
+                ..Default::default()
+            },
+        );
+    });
+}
@@ -0,0 +1,76 @@
+use leptos::prelude::*;
+
+use crate::{validation::FormValidation, FieldState};
+
+/// RangeSlider component for numeric form fields.
+///
+/// This component renders an `<input type="range">` whose track runs between
+/// the field's `range` validator bounds, stepping by its `multiple_of`, like
+/// `NumberInput`. Either can be set explicitly instead. Without a bound the
+/// browser's default track of 0 to 100 is used.
+///
+/// The field is updated on every `input` event while the thumb is dragged, and
+/// it sets the same ARIA attributes as `Input`.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="volume" let:field_state>
+///         <RangeSlider state=field_state class="slider" />
+///     </Field>
+///     <Field form=form name="ratio" let:field_state>
+///         <RangeSlider state=field_state min=0.0 max=1.0 step=0.05 />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn RangeSlider<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    /// Start of the track (defaults to the field's `range` minimum)
+    #[prop(optional, into)]
+    min: Option<f64>,
+    /// End of the track (defaults to the field's `range` maximum)
+    #[prop(optional, into)]
+    max: Option<f64>,
+    /// Step between values (defaults to the field's `multiple_of`)
+    #[prop(optional, into)]
+    step: Option<f64>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bounds = T::number_bounds(&state.field_name);
+    let min = min
+        .map(|min| min.to_string())
+        .or_else(|| bounds.min.map(|min| min.to_string()));
+    let max = max
+        .map(|max| max.to_string())
+        .or_else(|| bounds.max.map(|max| max.to_string()));
+    let step = step
+        .map(|step| step.to_string())
+        .or_else(|| bounds.step.map(|step| step.to_string()));
+
+    view! {
+        <input
+            id=id
+            type="range"
+            min=min
+            max=max
+            step=step
+            prop:value=move || value.get()
+            on:input=move |ev| value.set(event_target_value(&ev))
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
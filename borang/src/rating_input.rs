@@ -0,0 +1,142 @@
+use leptos::prelude::*;
+
+use crate::{
+    validation::{FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// RatingInput component for star ratings in `u32` form fields.
+///
+/// This component renders a `role="radiogroup"` of star buttons, one per
+/// point up to the field's `range` maximum, or `max` when set, or 5. Clicking
+/// a star sets the rating. The star of the rating gets `aria-checked="true"`,
+/// and every star up to it gets `data-filled="true"` for styling. Only the
+/// checked star is in the tab order. The arrow keys move the rating one star
+/// at a time, within the field's `range` minimum (or 1) and the maximum, and
+/// Home and End jump to either end.
+///
+/// The star buttons show `★` unless `symbol` is set, and are labelled
+/// "1 star", "2 stars" and so on for screen readers.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FormValidation, Clone, Default)]
+/// struct Review {
+///     #[validator(range(min = 1, max = 5))]
+///     stars: u32,
+/// }
+///
+/// view! {
+///     <Field form=form name="stars" let:field_state>
+///         <RatingInput state=field_state star_class="star" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn RatingInput<T>(
+    state: FieldState<T>,
+    /// Id of the `radiogroup` `<div>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// Class of the element wrapping the stars
+    #[prop(into, optional)]
+    class: &'static str,
+    #[prop(into, optional)] star_class: &'static str,
+    /// Number of stars (defaults to the field's `range` maximum, or 5)
+    #[prop(optional)]
+    max: Option<u32>,
+    /// Text of each star (defaults to "★")
+    #[prop(optional)]
+    symbol: Option<&'static str>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bounds = T::number_bounds(&state.field_name);
+    let max = max
+        .or_else(|| bounds.max.and_then(|max| max.to_u32()))
+        .unwrap_or(5)
+        .max(1);
+    let min = bounds
+        .min
+        .and_then(|min| min.to_u32())
+        .unwrap_or(1)
+        .clamp(1, max);
+    let symbol = symbol.unwrap_or("★");
+    let stars: Vec<NodeRef<leptos::html::Button>> = (0..max).map(|_| NodeRef::new()).collect();
+    let stars = StoredValue::new(stars);
+
+    let rating = Signal::derive({
+        let name = state.field_name.clone();
+        move || u32::from_field_value(&name, &value.get()).ok()
+    });
+    let set_rating = move |rating: u32| {
+        value.set(rating.to_field_value());
+        // Keep the focus on the checked star, as it is the only one in the tab
+        // order
+        let star = stars.with_value(|stars| stars.get(rating as usize - 1).copied());
+        if let Some(button) = star.and_then(|star| star.get()) {
+            let _ = button.focus();
+        }
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let current = rating.get_untracked();
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowUp" => current.map_or(min, |rating| rating + 1),
+            "ArrowLeft" | "ArrowDown" => current.map_or(min, |rating| rating.saturating_sub(1)),
+            "Home" => min,
+            "End" => max,
+            _ => return,
+        };
+        ev.prevent_default();
+        set_rating(next.clamp(min, max));
+    };
+
+    let buttons = (1..=max)
+        .map(|star| {
+            let node_ref = stars.with_value(|stars| stars[star as usize - 1]);
+            let label = if star == 1 {
+                "1 star".to_string()
+            } else {
+                format!("{} stars", star)
+            };
+            let filled = move || rating.get().is_some_and(|rating| star <= rating);
+            // The checked star, or the first one while there is no rating
+            let focusable = move || rating.get().unwrap_or(1).clamp(1, max) == star;
+            view! {
+                <button
+                    node_ref=node_ref
+                    type="button"
+                    role="radio"
+                    aria-label=label
+                    aria-checked=move || (rating.get() == Some(star)).to_string()
+                    data-filled=move || filled().to_string()
+                    tabindex=move || if focusable() { "0" } else { "-1" }
+                    on:click=move |_| set_rating(star)
+                    class=star_class
+                >
+                    {symbol}
+                </button>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div
+            role="radiogroup"
+            id=id
+            on:keydown=on_keydown
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:focusout=move |_| state.mark_touched()
+        >
+            {buttons}
+        </div>
+    }
+}
@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::validation::{
+    is_valid_email, text_length, ErrorKind, FromFieldValue, RangeBound, ValidationError,
+    ValidationResult,
+};
+
+/// A reusable validation rule for values of type `T`.
+///
+/// Rules let validation be built programmatically and shared between forms.
+/// They plug into the derive with `#[validator(rule = "...")]`, where the
+/// string is an expression evaluating to a rule for the field's type:
+///
+/// ```rust,ignore
+/// use borang::rules::{Length, Required, ValidationRule};
+///
+/// fn username_rules() -> Vec<Box<dyn ValidationRule<String>>> {
+///     vec![Box::new(Required), Box::new(Length::between(3, 20))]
+/// }
+///
+/// #[derive(FormValidation, Default, Clone)]
+/// struct Signup {
+///     #[validator(rule = "username_rules()")]
+///     username: String,
+/// }
+/// ```
+///
+/// Closures with the signature `Fn(&str, &T) -> ValidationResult` are rules
+/// too, so one-off checks need no dedicated type.
+pub trait ValidationRule<T: ?Sized> {
+    /// Validate a field's value.
+    ///
+    /// # Parameters
+    ///
+    /// - `field_name`: The name of the field being validated (for errors)
+    /// - `value`: The field's value
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult;
+}
+
+impl<T: ?Sized, F> ValidationRule<T> for F
+where
+    F: Fn(&str, &T) -> ValidationResult,
+{
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        self(field_name, value)
+    }
+}
+
+impl<T: ?Sized> ValidationRule<T> for Box<dyn ValidationRule<T>> {
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        (**self).validate(field_name, value)
+    }
+}
+
+/// A list of rules runs each in order, stopping at the first error
+impl<T: ?Sized, R: ValidationRule<T>> ValidationRule<T> for [R] {
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        self.iter()
+            .try_for_each(|rule| rule.validate(field_name, value))
+    }
+}
+
+impl<T: ?Sized, R: ValidationRule<T>> ValidationRule<T> for Vec<R> {
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        self.as_slice().validate(field_name, value)
+    }
+}
+
+/// The value must not be empty or whitespace only.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Required;
+
+impl ValidationRule<str> for Required {
+    fn validate(&self, field_name: &str, value: &str) -> ValidationResult {
+        if value.trim().is_empty() {
+            return Err(ValidationError::with_kind(ErrorKind::Required {
+                field: field_name.to_string(),
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// The value must be an email address. Empty values pass; combine with
+/// `Required` to reject them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Email;
+
+impl ValidationRule<str> for Email {
+    fn validate(&self, field_name: &str, value: &str) -> ValidationResult {
+        if !value.is_empty() && !is_valid_email(value) {
+            return Err(ValidationError::with_kind(ErrorKind::InvalidEmail {
+                field: field_name.to_string(),
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// The value's length must be within bounds, counted like the `length`
+/// validator (see `text_length`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Length {
+    /// Minimum length, inclusive
+    pub min: Option<usize>,
+    /// Maximum length, inclusive
+    pub max: Option<usize>,
+    /// Exact length
+    pub equal: Option<usize>,
+}
+
+impl Length {
+    /// At least `min` characters
+    pub fn min(min: usize) -> Self {
+        Self {
+            min: Some(min),
+            ..Self::default()
+        }
+    }
+
+    /// At most `max` characters
+    pub fn max(max: usize) -> Self {
+        Self {
+            max: Some(max),
+            ..Self::default()
+        }
+    }
+
+    /// Between `min` and `max` characters, inclusive
+    pub fn between(min: usize, max: usize) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+            equal: None,
+        }
+    }
+
+    /// Exactly `equal` characters
+    pub fn equal(equal: usize) -> Self {
+        Self {
+            equal: Some(equal),
+            ..Self::default()
+        }
+    }
+}
+
+impl ValidationRule<str> for Length {
+    fn validate(&self, field_name: &str, value: &str) -> ValidationResult {
+        let length = text_length(value);
+        let too_short = self.min.is_some_and(|min| length < min);
+        let too_long = self.max.is_some_and(|max| length > max);
+        let not_equal = self.equal.is_some_and(|equal| length != equal);
+        if too_short || too_long || not_equal {
+            return Err(ValidationError::with_kind(ErrorKind::InvalidLength {
+                field: field_name.to_string(),
+                min: self.min,
+                max: self.max,
+                equal: self.equal,
+            }));
+        }
+        Ok(())
+    }
+}
+
+// Rules checking the text of a value apply to any field type through its
+// string form, so `Required` works on `String` and `Option<i32>` alike
+macro_rules! impl_text_rule {
+    ($($rule:ty),*) => {
+        $(
+            impl<T: FromFieldValue> ValidationRule<T> for $rule {
+                fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+                    ValidationRule::<str>::validate(self, field_name, &value.to_field_value())
+                }
+            }
+        )*
+    };
+}
+
+impl_text_rule!(Required, Email, Length);
+
+/// The value must be within bounds, inclusive.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Range<T> {
+    /// Minimum value, inclusive
+    pub min: Option<T>,
+    /// Maximum value, inclusive
+    pub max: Option<T>,
+}
+
+impl<T> Range<T> {
+    /// At least `min`
+    pub fn min(min: T) -> Self {
+        Self {
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    /// At most `max`
+    pub fn max(max: T) -> Self {
+        Self {
+            min: None,
+            max: Some(max),
+        }
+    }
+
+    /// Between `min` and `max`, inclusive
+    pub fn between(min: T, max: T) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+}
+
+impl<T> ValidationRule<T> for Range<T>
+where
+    T: PartialOrd + Copy + Into<RangeBound>,
+{
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        let too_small = self.min.is_some_and(|min| *value < min);
+        let too_large = self.max.is_some_and(|max| *value > max);
+        if too_small || too_large {
+            return Err(ValidationError::with_kind(ErrorKind::InvalidRange {
+                field: field_name.to_string(),
+                min: self.min.map(Into::into),
+                max: self.max.map(Into::into),
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// Passes if either rule passes, otherwise fails with the first rule's error.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // A contact field accepting an email address or a phone number
+/// let rule = Or::new(Email, |field: &str, value: &String| check_phone(field, value));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Or<A, B> {
+    /// Combine two rules, either of which may pass
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T: ?Sized, A: ValidationRule<T>, B: ValidationRule<T>> ValidationRule<T> for Or<A, B> {
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        match self.first.validate(field_name, value) {
+            Ok(()) => Ok(()),
+            Err(error) => self.second.validate(field_name, value).map_err(|_| error),
+        }
+    }
+}
+
+/// Passes if the inner rule fails, otherwise fails with `message`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let rule = Not::new(Email, "Use a username, not an email address");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Not<R> {
+    rule: R,
+    message: String,
+}
+
+impl<R> Not<R> {
+    /// Invert a rule, failing with `message` when it passes
+    pub fn new(rule: R, message: impl Into<String>) -> Self {
+        Self {
+            rule,
+            message: message.into(),
+        }
+    }
+}
+
+impl<T: ?Sized, R: ValidationRule<T>> ValidationRule<T> for Not<R> {
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        match self.rule.validate(field_name, value) {
+            Ok(()) => Err(ValidationError::new(field_name, self.message.clone())),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Only applies the inner rule when a condition holds.
+///
+/// The condition is decided when the rule is built, so in the derive it can
+/// depend on other fields:
+///
+/// ```rust,ignore
+/// #[derive(FormValidation, Default, Clone)]
+/// struct Address {
+///     country: String,
+///     #[validator(rule = "When::new(self.country == \"MY\", Length::equal(5))")]
+///     postcode: String,
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct When<R> {
+    condition: bool,
+    rule: R,
+}
+
+impl<R> When<R> {
+    /// Apply `rule` only if `condition` is true
+    pub fn new(condition: bool, rule: R) -> Self {
+        Self { condition, rule }
+    }
+}
+
+impl<T: ?Sized, R: ValidationRule<T>> ValidationRule<T> for When<R> {
+    fn validate(&self, field_name: &str, value: &T) -> ValidationResult {
+        if self.condition {
+            self.rule.validate(field_name, value)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A rule in the registry, checking a field's string value
+type RegisteredRule = Arc<dyn ValidationRule<str> + Send + Sync>;
+
+/// Rules registered by name with `register_rule`
+fn registry() -> &'static RwLock<HashMap<String, RegisteredRule>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, RegisteredRule>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Register a rule under a name, for use with `#[validator(registered = "...")]`.
+///
+/// Call this at startup, before any form is validated, so rules shared by many
+/// forms live in one place. Registered rules check the field's string value.
+/// Registering a name again replaces the previous rule.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use borang::rules::{register_rule, Length};
+///
+/// register_rule("strong_password", Length::min(12));
+///
+/// #[derive(FormValidation, Default, Clone)]
+/// struct Signup {
+///     #[validator(registered = "strong_password")]
+///     password: String,
+/// }
+/// ```
+pub fn register_rule(
+    name: impl Into<String>,
+    rule: impl ValidationRule<str> + Send + Sync + 'static,
+) {
+    registry()
+        .write()
+        .unwrap()
+        .insert(name.into(), Arc::new(rule));
+}
+
+/// Validate a value with the rule registered under `name`.
+///
+/// If no rule is registered under `name`, the value fails with the
+/// `unregistered_rule` code and a `rule` param holding the name, so a missing
+/// or misspelled rule keeps the form from submitting instead of silently
+/// skipping validation or panicking while the user types.
+pub fn validate_registered(name: &str, field_name: &str, value: &str) -> ValidationResult {
+    // Clone the rule out so it can't deadlock by registering rules itself
+    let rule = registry().read().unwrap().get(name).cloned();
+    match rule {
+        Some(rule) => rule.validate(field_name, value),
+        None => Err(
+            ValidationError::custom_code(field_name, "unregistered_rule")
+                .with_param("rule", name)
+                .with_message(format!("no validation rule registered as '{}'", name)),
+        ),
+    }
+}
@@ -5,33 +5,69 @@ use crate::{validation::FormValidation, FieldState};
 /// Select component for form fields.
 ///
 /// This component binds the select value and sets up mark touched
-/// on blur to the FieldState.
+/// on blur to the FieldState. It sets the same ARIA attributes as `Input`.
+/// Use `MultiSelect` for a `<select multiple>`.
+///
+/// Like `Input`, other attributes are passed through with the `attr:` prefix.
+///
+/// Options are given as children, or as `(value, label)` pairs with `options`,
+/// e.g. from an enum's `FieldOptions`. Children come first, so a blank
+/// placeholder option can be combined with generated ones.
 ///
 /// # Example
 /// ```rust,ignore
 /// view! {
 ///     <Field form=form name="country" let:field_state>
-///         <Select state=field_state class="select-class">
+///         <Select state=field_state id="country" class="select-class">
 ///             <option value="us">"United States"</option>
 ///             <option value="uk">"United Kingdom"</option>
 ///             <option value="ca">"Canada"</option>
 ///         </Select>
 ///     </Field>
+///     <Field form=form name="region" let:field_state>
+///         <Select state=field_state options=Region::field_options()>
+///             <option value="">"Choose a region"</option>
+///         </Select>
+///     </Field>
 /// }
 /// ```
 #[component]
 pub fn Select<T>(
     state: FieldState<T>,
     #[prop(into, optional)] class: &'static str,
-    children: Children,
+    /// Id of the `<select>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(optional)] autocomplete: Option<&'static str>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Options to render after the children, as `(value, label)` pairs
+    #[prop(into, optional)]
+    options: Vec<(String, String)>,
+    #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView
 where
     T: FormValidation + Default + Clone + Send + Sync + 'static,
 {
     let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
     view! {
-        <select bind:value=value class=class on:blur=move |_| state.mark_touched()>
-            {children()}
+        <select
+            id=id
+            autocomplete=autocomplete
+            disabled=move || disabled.get().unwrap_or(false)
+            bind:value=value
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        >
+            {children.map(|children| children())}
+            {options
+                .into_iter()
+                .map(|(value, label)| view! { <option value=value>{label}</option> })
+                .collect_view()}
         </select>
     }
 }
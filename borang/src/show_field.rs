@@ -0,0 +1,56 @@
+use leptos::prelude::*;
+
+use super::form::{use_form, Form};
+use super::validation::FormValidation;
+
+/// ShowField component that only renders its children while a field is shown
+///
+/// Whether the field is shown comes from the rule registered with
+/// `Form::show_when`. When the field is hidden, its errors are cleared, so a
+/// hidden field never blocks the form from being valid.
+///
+/// # Example
+/// ```rust,ignore
+/// let form = Form::<MyForm>::new()
+///     .show_when("company_name", |values| {
+///         values.get("is_business").is_some_and(|v| v == "true")
+///     });
+///
+/// view! {
+///     <ShowField form=form name="company_name">
+///         <Field form=form name="company_name" let:field_state>
+///             <Input state=field_state />
+///         </Field>
+///     </ShowField>
+/// }
+/// ```
+#[component]
+pub fn ShowField<T>(
+    /// The form the field belongs to (defaults to the form of the enclosing
+    /// `FormComponent`)
+    #[prop(optional)]
+    form: Option<Form<T>>,
+    /// The name of the field whose `show_when` rule decides visibility
+    #[prop(into)]
+    name: Oco<'static, str>,
+    /// Content rendered while the field is shown
+    children: ChildrenFn,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let form = form.unwrap_or_else(use_form::<T>);
+    let visible = Signal::derive({
+        let name = name.clone();
+        move || form.is_field_visible(&name)
+    });
+
+    // Drop the field's errors as soon as it's hidden
+    Effect::new(move |_| {
+        if !visible.get() {
+            form.clear_field_error(&name);
+        }
+    });
+
+    view! { <Show when=move || visible.get()>{children()}</Show> }
+}
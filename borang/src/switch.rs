@@ -0,0 +1,68 @@
+use leptos::prelude::*;
+
+use crate::{
+    validation::{FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// Switch component for `bool` form fields.
+///
+/// This component renders a `<button role="switch">` that flips the field
+/// between `"true"` and `"false"` when clicked, an alternative look to
+/// `Checkbox` for settings forms. Being a button, it toggles with Space and
+/// Enter, and `aria-checked` tells screen readers its state. Its id defaults
+/// to the field name, so a `Label` names it.
+///
+/// The button holds a `<span>` for the thumb. Style the on state with the
+/// `[aria-checked="true"]` selector.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="notifications" let:field_state>
+///         <Label state=field_state.clone() />
+///         <Switch state=field_state class="switch" thumb_class="switch-thumb" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn Switch<T>(
+    state: FieldState<T>,
+    /// Id of the `<button>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(into, optional)] class: &'static str,
+    /// Class of the thumb inside the switch
+    #[prop(into, optional)]
+    thumb_class: &'static str,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let checked = Signal::derive({
+        let name = state.field_name.clone();
+        move || bool::from_field_value(&name, &value.get()).unwrap_or(false)
+    });
+    let toggle = move |_| value.set((!checked.get_untracked()).to_field_value());
+    view! {
+        <button
+            type="button"
+            role="switch"
+            id=id
+            aria-checked=move || checked.get().to_string()
+            disabled=move || disabled.get().unwrap_or(false)
+            on:click=toggle
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        >
+            <span class=thumb_class></span>
+        </button>
+    }
+}
@@ -0,0 +1,157 @@
+use leptos::prelude::*;
+
+use crate::{
+    validation::{FormValidation, FromFieldValue},
+    FieldState,
+};
+
+/// TagsInput component for `Vec<String>` form fields.
+///
+/// This component renders the field's values as removable tags followed by a
+/// text input. Typing a tag and pressing Enter or a comma adds it, Backspace in
+/// the empty input removes the last tag, and pasted text is split into tags at
+/// commas and line breaks. Tags are trimmed, and empty or repeated ones are
+/// skipped. The field's value stays the comma-separated list `Vec<String>`
+/// fields parse. Typed tags can't contain commas, but tags set from code
+/// can, as the list escapes them.
+///
+/// The field's validators see the whole list, e.g. `items(max = 5)`. Check each
+/// tag with a `custom_value` or `rule` validator.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="tags" let:field_state>
+///         <TagsInput
+///             state=field_state
+///             class="tags"
+///             tag_class="tag"
+///             placeholder="Add a tag"
+///         />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn TagsInput<T>(
+    state: FieldState<T>,
+    /// Id of the `<input>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    /// Class of the element wrapping the tags and the input
+    #[prop(into, optional)]
+    class: &'static str,
+    #[prop(into, optional)] tag_class: &'static str,
+    #[prop(into, optional)] input_class: &'static str,
+    #[prop(optional)] placeholder: Option<&'static str>,
+    /// Accessible label of each tag's remove button, followed by the tag
+    /// (defaults to "Remove")
+    #[prop(optional)]
+    remove_label: Option<&'static str>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let draft = RwSignal::new(String::new());
+    let remove_label = remove_label.unwrap_or("Remove");
+
+    let tags = Signal::derive({
+        let name = state.field_name.clone();
+        move || Vec::<String>::from_field_value(&name, &value.get()).unwrap_or_default()
+    });
+    let current_tags = move || tags.get_untracked();
+    let add_tags = move |text: &str| {
+        let mut tags = current_tags();
+        for tag in text.split([',', '\n']).map(str::trim) {
+            if !tag.is_empty() && !tags.iter().any(|existing| existing == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+        value.set(tags.to_field_value());
+    };
+    let remove_tag = move |index: usize| {
+        let mut tags = current_tags();
+        if index < tags.len() {
+            tags.remove(index);
+            value.set(tags.to_field_value());
+        }
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| match ev.key().as_str() {
+        "Enter" | "," => {
+            ev.prevent_default();
+            add_tags(&draft.get_untracked());
+            draft.set(String::new());
+        }
+        "Backspace" if draft.with_untracked(String::is_empty) => {
+            let count = current_tags().len();
+            if count > 0 {
+                remove_tag(count - 1);
+            }
+        }
+        _ => {}
+    };
+    let on_paste = move |ev: leptos::ev::ClipboardEvent| {
+        let text = ev
+            .clipboard_data()
+            .and_then(|data| data.get_data("text").ok())
+            .unwrap_or_default();
+        if text.contains([',', '\n']) {
+            ev.prevent_default();
+            add_tags(&format!("{},{}", draft.get_untracked(), text));
+            draft.set(String::new());
+        }
+    };
+    let on_blur = {
+        let state = state.clone();
+        move |_| {
+            // Keep a tag that was typed but not confirmed
+            if !draft.with_untracked(String::is_empty) {
+                add_tags(&draft.get_untracked());
+                draft.set(String::new());
+            }
+            state.mark_touched();
+        }
+    };
+
+    view! {
+        <div class=class>
+            {move || {
+                tags.get()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, tag)| {
+                        let label = format!("{} {}", remove_label, tag);
+                        view! {
+                            <span class=tag_class>
+                                {tag}
+                                <button
+                                    type="button"
+                                    aria-label=label
+                                    on:click=move |_| remove_tag(index)
+                                >
+                                    "×"
+                                </button>
+                            </span>
+                        }
+                    })
+                    .collect_view()
+            }}
+            <input
+                type="text"
+                id=id
+                placeholder=placeholder
+                bind:value=draft
+                on:keydown=on_keydown
+                on:paste=on_paste
+                class=input_class
+                aria-required=state.is_required().then_some("true")
+                aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+                aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+                on:blur=on_blur
+            />
+        </div>
+    }
+}
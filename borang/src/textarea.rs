@@ -0,0 +1,97 @@
+use leptos::prelude::*;
+
+use crate::{
+    form::BindOn,
+    validation::{FormValidation, TextBounds},
+    FieldState,
+};
+
+/// Textarea component for multi-line form fields.
+///
+/// This component renders a `<textarea>` bound like `Input`, with the same
+/// ARIA attributes. Its `required`, `minlength` and `maxlength` attributes
+/// come from the field's `required` and `length` validators. Other attributes,
+/// such as `rows`, are passed through with the `attr:` prefix. Like `Input`, it
+/// shows fields with a `FieldFormat` formatted.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="bio" let:field_state>
+///         <Textarea state=field_state id="bio" class="textarea-class" attr:rows="4" />
+///     </Field>
+/// }
+/// ```
+#[component]
+pub fn Textarea<T>(
+    state: FieldState<T>,
+    #[prop(into, optional)] class: &'static str,
+    #[prop(optional, into)] bind_on: Option<BindOn>,
+    /// Id of the `<textarea>` (defaults to the field name)
+    #[prop(optional, into)]
+    id: Option<Oco<'static, str>>,
+    #[prop(optional)] placeholder: Option<&'static str>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+) -> impl IntoView
+where
+    T: FormValidation + Default + Clone + Send + Sync + 'static,
+{
+    let value = state.value();
+    let error_id = state.error_id();
+    let id = id.unwrap_or_else(|| state.field_name.clone());
+    let bind_on = bind_on.unwrap_or(state.bind_on);
+    // Length limits apply to the stored value, not the formatted text
+    let text = if state.format.is_some() {
+        TextBounds::default()
+    } else {
+        T::text_bounds(&state.field_name)
+    };
+    let node_ref = NodeRef::<leptos::html::Textarea>::new();
+    let set_value = Callback::new({
+        let state = state.clone();
+        move |typed: String| {
+            value.set(state.parse_input(&typed));
+            // Show the formatted value, even when typing a character the format
+            // drops leaves the stored value as it was
+            if state.format.is_some() {
+                if let Some(element) = node_ref.get_untracked() {
+                    let shown = state.format_value(&value.get_untracked());
+                    if element.value() != shown {
+                        element.set_value(&shown);
+                    }
+                }
+            }
+        }
+    });
+    let shown = {
+        let state = state.clone();
+        move || state.format_value(&value.get())
+    };
+    view! {
+        <textarea
+            id=id
+            placeholder=placeholder
+            disabled=move || disabled.get().unwrap_or(false)
+            required=state.is_required()
+            minlength=text.min_length.map(|min| min.to_string())
+            maxlength=text.max_length.map(|max| max.to_string())
+            node_ref=node_ref
+            prop:value=shown
+            on:input=move |ev| {
+                if bind_on == BindOn::Input {
+                    set_value.run(event_target_value(&ev));
+                }
+            }
+            on:change=move |ev| {
+                if bind_on == BindOn::Change {
+                    set_value.run(event_target_value(&ev));
+                }
+            }
+            class=class
+            aria-required=state.is_required().then_some("true")
+            aria-invalid=move || state.visible_error.with(Option::is_some).then_some("true")
+            aria-describedby=move || state.visible_error.with(Option::is_some).then(|| error_id.clone())
+            on:blur=move |_| state.mark_touched()
+        />
+    }
+}
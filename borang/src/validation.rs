@@ -1,5 +1,7 @@
 use leptos::prelude::*;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
 
 /// A field's reactive value signal.
 ///
@@ -12,6 +14,321 @@ pub struct FieldSignal {
     pub value: RwSignal<String>,
 }
 
+/// Field signals serialize as their current value
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldSignal {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value
+            .with_untracked(|value| serializer.serialize_str(value))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FieldSignal {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(FieldSignal {
+            value: RwSignal::new(value),
+        })
+    }
+}
+
+/// A numeric bound reported by range validation errors.
+///
+/// Bounds keep the literal kind they were declared with, so float bounds
+/// like `range(min = 0.5)`, `u64` bounds above `i64::MAX` and the bounds of
+/// `Decimal` fields are reported without loss.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeBound {
+    /// Integer bound
+    Int(i64),
+    /// Unsigned integer bound too large for `i64`
+    UInt(u64),
+    /// Floating-point bound
+    Float(f64),
+    /// Exact decimal bound of a `rust_decimal::Decimal` field
+    #[cfg(feature = "decimal")]
+    Decimal(rust_decimal::Decimal),
+}
+
+impl RangeBound {
+    /// Get the bound as a `u32`, or `None` if it is negative, too large or
+    /// not a whole number
+    pub fn to_u32(&self) -> Option<u32> {
+        match *self {
+            RangeBound::Int(v) => u32::try_from(v).ok(),
+            RangeBound::UInt(v) => u32::try_from(v).ok(),
+            RangeBound::Float(v) => {
+                (v.fract() == 0.0 && (0.0..=f64::from(u32::MAX)).contains(&v)).then_some(v as u32)
+            }
+            #[cfg(feature = "decimal")]
+            RangeBound::Decimal(v) => {
+                use rust_decimal::prelude::ToPrimitive;
+                v.fract().is_zero().then(|| v.to_u32()).flatten()
+            }
+        }
+    }
+}
+
+impl fmt::Display for RangeBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeBound::Int(v) => write!(f, "{}", v),
+            RangeBound::UInt(v) => write!(f, "{}", v),
+            RangeBound::Float(v) => write!(f, "{}", v),
+            #[cfg(feature = "decimal")]
+            RangeBound::Decimal(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+// Conversions used when a validator bound is a constant, e.g. `range(max = MAX_AGE)`
+macro_rules! impl_range_bound_from {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for RangeBound {
+                fn from(value: $ty) -> Self {
+                    RangeBound::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_range_bound_from!(Int: i8, i16, i32, i64, u8, u16, u32);
+impl_range_bound_from!(Float: f32, f64);
+
+impl From<u64> for RangeBound {
+    fn from(value: u64) -> Self {
+        i64::try_from(value)
+            .map(RangeBound::Int)
+            .unwrap_or(RangeBound::UInt(value))
+    }
+}
+
+impl From<usize> for RangeBound {
+    fn from(value: usize) -> Self {
+        RangeBound::from(value as u64)
+    }
+}
+
+impl From<isize> for RangeBound {
+    fn from(value: isize) -> Self {
+        RangeBound::Int(value as i64)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<rust_decimal::Decimal> for RangeBound {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        RangeBound::Decimal(value)
+    }
+}
+
+/// The numeric limits of a field, from its `range` and `multiple_of`
+/// validators.
+///
+/// `NumberInput` uses these as its `min`, `max` and `step` attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NumberBounds {
+    /// Smallest allowed value
+    pub min: Option<RangeBound>,
+    /// Largest allowed value
+    pub max: Option<RangeBound>,
+    /// The value must be a multiple of this
+    pub step: Option<RangeBound>,
+}
+
+/// Information about a form field, returned by `FormValidation::field_meta`.
+///
+/// This describes a form without an instance of it, e.g. to render default
+/// inputs, set HTML attributes or export a schema.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FieldMeta {
+    /// The field's key in the form
+    pub name: &'static str,
+    /// The field's type as written in the struct, e.g. `Option<u32>`
+    pub type_name: &'static str,
+    /// The label from `#[field(label = "...")]`
+    pub label: Option<&'static str>,
+    /// True if the field has a `required` validator that isn't a warning
+    pub required: bool,
+    /// The field's validators, in declaration order
+    pub validators: Vec<ValidatorMeta>,
+    /// True for `#[field(nested)]` and `#[field(array)]` fields, whose values
+    /// live in fields of their own
+    pub compound: bool,
+}
+
+/// A validator of a form field, listed in `FieldMeta`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ValidatorMeta {
+    /// The validator's name as used in `#[validator(...)]`, e.g. `length`
+    pub name: &'static str,
+    /// The validator's parameters, with constants evaluated, e.g.
+    /// `("min", "8")`. Names repeat for lists, like the values of `one_of`.
+    pub params: Vec<(&'static str, String)>,
+    /// True for `severity = "warning"`
+    pub warning: bool,
+}
+
+impl ValidatorMeta {
+    /// Get the value of a parameter by name
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The text limits of a field, from its `length`, `pattern` and `password`
+/// validators.
+///
+/// `Input` and `Textarea` use these as their `minlength`, `maxlength` and
+/// `pattern` attributes, and `PasswordInput` scores its strength meter against
+/// the password policy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextBounds {
+    /// Fewest characters allowed
+    pub min_length: Option<usize>,
+    /// Most characters allowed
+    pub max_length: Option<usize>,
+    /// The `pattern` regex, rewritten for the HTML attribute, which must match
+    /// the whole value
+    pub pattern: Option<&'static str>,
+    /// The `password` validator's minimum length and number of character
+    /// classes
+    pub password: Option<(usize, usize)>,
+}
+
+/// How a field's value is shown in its input, from `#[field(mask = "...")]` or
+/// the `Field` component's `format` prop.
+///
+/// The form stores and validates the canonical value, e.g. `123456`, while
+/// `Input` and `Textarea` show the formatted one, e.g. `12-3456`.
+#[derive(Clone, Copy, Debug)]
+pub enum FieldFormat {
+    /// A mask like `"####-####-####-####"`, where `#` takes a digit, `A` a
+    /// letter and `*` a letter or digit. Other characters are shown as they
+    /// are and left out of the stored value.
+    Mask(&'static str),
+    /// Functions turning the stored value into the shown text and back
+    Custom {
+        format: fn(&str) -> String,
+        parse: fn(&str) -> String,
+    },
+}
+
+impl FieldFormat {
+    /// Get the text to show for a stored value
+    ///
+    /// A mask's characters are added as far as the value reaches, and
+    /// characters beyond the mask are shown after it.
+    pub fn format(&self, value: &str) -> String {
+        match self {
+            FieldFormat::Mask(mask) => {
+                let mut chars = value.chars().peekable();
+                let mut text = String::new();
+                for slot in mask.chars() {
+                    if chars.peek().is_none() {
+                        break;
+                    }
+                    if is_mask_slot(slot) {
+                        text.extend(chars.next());
+                    } else {
+                        text.push(slot);
+                    }
+                }
+                text.extend(chars);
+                text
+            }
+            FieldFormat::Custom { format, .. } => format(value),
+        }
+    }
+
+    /// Get the value to store for text typed into an input
+    ///
+    /// With a mask, characters its slots don't take are dropped, as are
+    /// characters beyond the mask.
+    pub fn parse(&self, text: &str) -> String {
+        match self {
+            FieldFormat::Mask(mask) => {
+                let mut slots = mask.chars().peekable();
+                let mut value = String::new();
+                'text: for c in text.chars() {
+                    // Step over the mask's own characters, whether or not they
+                    // were typed
+                    while let Some(&slot) = slots.peek() {
+                        if is_mask_slot(slot) {
+                            break;
+                        }
+                        slots.next();
+                        if slot == c {
+                            continue 'text;
+                        }
+                    }
+                    match slots.peek() {
+                        Some(&slot) if mask_slot_takes(slot, c) => {
+                            value.push(c);
+                            slots.next();
+                        }
+                        Some(_) => {}
+                        None => break,
+                    }
+                }
+                value
+            }
+            FieldFormat::Custom { parse, .. } => parse(text),
+        }
+    }
+}
+
+/// Check whether a mask character is a slot for typed characters
+fn is_mask_slot(slot: char) -> bool {
+    matches!(slot, '#' | 'A' | '*')
+}
+
+/// Check whether a mask slot takes a typed character
+fn mask_slot_takes(slot: char, c: char) -> bool {
+    match slot {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        _ => c.is_alphanumeric(),
+    }
+}
+
+/// The sign a numeric value must have, reported by sign validation errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignConstraint {
+    /// Greater than zero
+    Positive,
+    /// Zero or greater
+    NonNegative,
+    /// Anything but zero
+    NonZero,
+}
+
+/// A password requirement that was not met, reported by `ErrorKind::WeakPassword`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PasswordRequirement {
+    /// At least this many characters
+    MinLength(usize),
+    /// A lowercase letter
+    Lowercase,
+    /// An uppercase letter
+    Uppercase,
+    /// An ASCII digit (`0-9`)
+    Digit,
+    /// A character that is not a letter, digit or whitespace
+    Symbol,
+}
+
 /// Represents the kind of validation error that occurred.
 ///
 /// This enum categorizes validation errors and stores their parameters,
@@ -26,31 +343,86 @@ pub struct FieldSignal {
 ///     field: "email".to_string(),
 /// };
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ErrorKind {
     /// Field is required but empty
     Required { field: String },
     /// Email format is invalid
     InvalidEmail { field: String },
+    /// UUID format is invalid
+    InvalidUuid { field: String },
+    /// Hex color format is invalid
+    InvalidHexColor { field: String },
     /// String length constraints not met
     InvalidLength {
         field: String,
         min: Option<usize>,
         max: Option<usize>,
+        equal: Option<usize>,
+    },
+    /// Collection item count constraints not met
+    InvalidItemCount {
+        field: String,
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// Date is malformed or outside its bounds (bounds are exclusive, `YYYY-MM-DD`)
+    InvalidDate {
+        field: String,
+        after: Option<String>,
+        before: Option<String>,
     },
     /// Numeric range constraints not met
     InvalidRange {
         field: String,
-        min: Option<i64>,
-        max: Option<i64>,
+        min: Option<RangeBound>,
+        max: Option<RangeBound>,
+    },
+    /// Numeric value not a multiple of the required step
+    NotMultipleOf { field: String, step: RangeBound },
+    /// Password does not meet the strength policy
+    ///
+    /// `missing` lists each failed requirement, so a UI can render a checklist.
+    /// When too few character classes are used, every absent class is listed.
+    WeakPassword {
+        field: String,
+        missing: Vec<PasswordRequirement>,
+    },
+    /// Numeric sign constraint not met
+    InvalidSign { field: String, sign: SignConstraint },
+    /// A selected file is larger than allowed (`max_size` is in bytes)
+    FileTooLarge {
+        field: String,
+        file: String,
+        max_size: u64,
+    },
+    /// A selected file is not one of the accepted types
+    InvalidFileType {
+        field: String,
+        file: String,
+        accept: String,
     },
     /// Parse error (e.g., invalid number)
     ParseError {
         field: String,
         expected_type: String,
     },
+    /// Value does not match the required regex pattern
+    PatternMismatch { field: String, pattern: String },
+    /// Value is not one of the allowed values
+    NotOneOf { field: String, allowed: Vec<String> },
     /// Custom validation error
     Custom { field: String, message: String },
+    /// Custom validation error identified by a code, for keyed i18n
+    ///
+    /// `params` holds values to interpolate into the translated message,
+    /// e.g. `code: "username_taken"` with a `suggestion` param.
+    CustomCode {
+        field: String,
+        code: String,
+        params: HashMap<String, String>,
+    },
 }
 
 impl ErrorKind {
@@ -59,73 +431,277 @@ impl ErrorKind {
         match self {
             ErrorKind::Required { field } => field,
             ErrorKind::InvalidEmail { field } => field,
+            ErrorKind::InvalidUuid { field } => field,
+            ErrorKind::InvalidHexColor { field } => field,
             ErrorKind::InvalidLength { field, .. } => field,
+            ErrorKind::InvalidItemCount { field, .. } => field,
+            ErrorKind::InvalidDate { field, .. } => field,
             ErrorKind::InvalidRange { field, .. } => field,
+            ErrorKind::NotMultipleOf { field, .. } => field,
+            ErrorKind::InvalidSign { field, .. } => field,
+            ErrorKind::WeakPassword { field, .. } => field,
+            ErrorKind::FileTooLarge { field, .. } => field,
+            ErrorKind::InvalidFileType { field, .. } => field,
             ErrorKind::ParseError { field, .. } => field,
+            ErrorKind::PatternMismatch { field, .. } => field,
+            ErrorKind::NotOneOf { field, .. } => field,
             ErrorKind::Custom { field, .. } => field,
+            ErrorKind::CustomCode { field, .. } => field,
+        }
+    }
+
+    /// Get a stable, machine-readable code for this error.
+    ///
+    /// Codes name the validator, suffixed with the constraint that applies when
+    /// the validator has several, e.g. `"required"`, `"length.min"` or
+    /// `"range.between"`. `Custom` and `CustomCode` errors both have the
+    /// `"custom"` code; get a `CustomCode` error's own code with
+    /// `custom_code_name`. Unlike messages codes never change with the locale,
+    /// so server APIs and analytics can classify errors by them.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::Required { .. } => "required",
+            ErrorKind::InvalidEmail { .. } => "email",
+            ErrorKind::InvalidUuid { .. } => "uuid",
+            ErrorKind::InvalidHexColor { .. } => "hex_color",
+            ErrorKind::InvalidLength { equal: Some(_), .. } => "length.equal",
+            ErrorKind::InvalidLength {
+                min: Some(_),
+                max: Some(_),
+                ..
+            } => "length.between",
+            ErrorKind::InvalidLength { min: Some(_), .. } => "length.min",
+            ErrorKind::InvalidLength { max: Some(_), .. } => "length.max",
+            ErrorKind::InvalidLength { .. } => "length",
+            ErrorKind::InvalidItemCount {
+                min: Some(_),
+                max: Some(_),
+                ..
+            } => "items.between",
+            ErrorKind::InvalidItemCount { min: Some(_), .. } => "items.min",
+            ErrorKind::InvalidItemCount { max: Some(_), .. } => "items.max",
+            ErrorKind::InvalidItemCount { .. } => "items",
+            ErrorKind::InvalidDate {
+                after: Some(_),
+                before: Some(_),
+                ..
+            } => "date.between",
+            ErrorKind::InvalidDate { after: Some(_), .. } => "date.after",
+            ErrorKind::InvalidDate {
+                before: Some(_), ..
+            } => "date.before",
+            ErrorKind::InvalidDate { .. } => "date",
+            ErrorKind::InvalidRange {
+                min: Some(_),
+                max: Some(_),
+                ..
+            } => "range.between",
+            ErrorKind::InvalidRange { min: Some(_), .. } => "range.min",
+            ErrorKind::InvalidRange { max: Some(_), .. } => "range.max",
+            ErrorKind::InvalidRange { .. } => "range",
+            ErrorKind::NotMultipleOf { .. } => "multiple_of",
+            ErrorKind::InvalidSign { sign, .. } => match sign {
+                SignConstraint::Positive => "positive",
+                SignConstraint::NonNegative => "non_negative",
+                SignConstraint::NonZero => "non_zero",
+            },
+            ErrorKind::WeakPassword { .. } => "password",
+            ErrorKind::FileTooLarge { .. } => "file.max_size",
+            ErrorKind::InvalidFileType { .. } => "file.accept",
+            ErrorKind::ParseError { .. } => "parse",
+            ErrorKind::PatternMismatch { .. } => "pattern",
+            ErrorKind::NotOneOf { .. } => "one_of",
+            ErrorKind::Custom { .. } | ErrorKind::CustomCode { .. } => "custom",
+        }
+    }
+
+    /// Get the code a `CustomCode` error was created with, e.g.
+    /// `"username_taken"`, or `None` for every other kind.
+    pub fn custom_code_name(&self) -> Option<&str> {
+        match self {
+            ErrorKind::CustomCode { code, .. } => Some(code),
+            _ => None,
         }
     }
 
     /// Get the default English error message (for backward compatibility).
     pub fn default_message(&self) -> String {
+        self.default_message_with_label(self.field())
+    }
+
+    /// Get the default English error message, naming the field by `label`.
+    ///
+    /// Used for fields declared with `#[field(label = "...")]`, so messages read
+    /// "Email address is required" rather than "email_address is required".
+    pub fn default_message_with_label(&self, label: &str) -> String {
         match self {
-            ErrorKind::Required { field } => format!("{} is required", field),
-            ErrorKind::InvalidEmail { field } => format!("{} must be a valid email address", field),
+            ErrorKind::Required { .. } => format!("{} is required", label),
+            ErrorKind::InvalidEmail { .. } => format!("{} must be a valid email address", label),
+            ErrorKind::InvalidUuid { .. } => format!("{} must be a valid UUID", label),
+            ErrorKind::InvalidHexColor { .. } => format!("{} must be a valid hex color", label),
+            ErrorKind::InvalidLength {
+                equal: Some(equal), ..
+            } => {
+                format!("{} must be exactly {} characters", label, equal)
+            }
             ErrorKind::InvalidLength {
-                field,
                 min: Some(min),
                 max: Some(max),
+                ..
             } => {
-                format!("{} must be between {} and {} characters", field, min, max)
+                format!("{} must be between {} and {} characters", label, min, max)
             }
             ErrorKind::InvalidLength {
-                field,
                 min: Some(min),
                 max: None,
+                ..
             } => {
-                format!("{} must be at least {} characters", field, min)
+                format!("{} must be at least {} characters", label, min)
             }
             ErrorKind::InvalidLength {
-                field,
                 min: None,
                 max: Some(max),
+                ..
             } => {
-                format!("{} must be at most {} characters", field, max)
+                format!("{} must be at most {} characters", label, max)
             }
-            ErrorKind::InvalidLength { field, .. } => format!("{} has invalid length", field),
+            ErrorKind::InvalidLength { .. } => format!("{} has invalid length", label),
+            ErrorKind::InvalidItemCount {
+                min: Some(min),
+                max: Some(max),
+                ..
+            } => {
+                format!("{} must have between {} and {} items", label, min, max)
+            }
+            ErrorKind::InvalidItemCount {
+                min: Some(min),
+                max: None,
+                ..
+            } => {
+                format!("{} must have at least {} items", label, min)
+            }
+            ErrorKind::InvalidItemCount {
+                min: None,
+                max: Some(max),
+                ..
+            } => {
+                format!("{} must have at most {} items", label, max)
+            }
+            ErrorKind::InvalidItemCount { .. } => {
+                format!("{} has an invalid number of items", label)
+            }
+            ErrorKind::InvalidDate {
+                after: Some(after),
+                before: Some(before),
+                ..
+            } => {
+                format!("{} must be between {} and {}", label, after, before)
+            }
+            ErrorKind::InvalidDate {
+                after: Some(after),
+                before: None,
+                ..
+            } => {
+                format!("{} must be after {}", label, after)
+            }
+            ErrorKind::InvalidDate {
+                after: None,
+                before: Some(before),
+                ..
+            } => {
+                format!("{} must be before {}", label, before)
+            }
+            ErrorKind::InvalidDate { .. } => format!("{} must be a valid date", label),
             ErrorKind::InvalidRange {
-                field,
                 min: Some(min),
                 max: Some(max),
+                ..
             } => {
-                format!("{} must be between {} and {}", field, min, max)
+                format!("{} must be between {} and {}", label, min, max)
             }
             ErrorKind::InvalidRange {
-                field,
                 min: Some(min),
                 max: None,
+                ..
             } => {
-                format!("{} must be at least {}", field, min)
+                format!("{} must be at least {}", label, min)
             }
             ErrorKind::InvalidRange {
-                field,
                 min: None,
                 max: Some(max),
+                ..
             } => {
-                format!("{} must be at most {}", field, max)
+                format!("{} must be at most {}", label, max)
             }
-            ErrorKind::InvalidRange { field, .. } => format!("{} is out of range", field),
-            ErrorKind::ParseError {
-                field,
-                expected_type,
-            } => {
-                format!("{} must be a valid {}", field, expected_type)
+            ErrorKind::InvalidRange { .. } => format!("{} is out of range", label),
+            ErrorKind::NotMultipleOf { step, .. } => {
+                format!("{} must be a multiple of {}", label, step)
+            }
+            ErrorKind::WeakPassword { missing, .. } => {
+                match missing.iter().find_map(|req| match req {
+                    PasswordRequirement::MinLength(min) => Some(*min),
+                    _ => None,
+                }) {
+                    Some(min) => format!("{} must be at least {} characters", label, min),
+                    None => format!(
+                        "{} must mix lowercase, uppercase, digits and symbols",
+                        label
+                    ),
+                }
+            }
+            ErrorKind::FileTooLarge { max_size, .. } => {
+                let megabytes = *max_size as f64 / BYTES_PER_MEGABYTE as f64;
+                format!("{} must be at most {} MB", label, megabytes)
+            }
+            ErrorKind::InvalidFileType { accept, .. } => {
+                format!("{} must be a file of type {}", label, accept)
+            }
+            ErrorKind::InvalidSign { sign, .. } => match sign {
+                SignConstraint::Positive => format!("{} must be positive", label),
+                SignConstraint::NonNegative => format!("{} must not be negative", label),
+                SignConstraint::NonZero => format!("{} must not be zero", label),
+            },
+            ErrorKind::ParseError { expected_type, .. } => {
+                format!("{} must be a valid {}", label, expected_type)
+            }
+            ErrorKind::PatternMismatch { .. } => {
+                format!("{} has an invalid format", label)
+            }
+            ErrorKind::NotOneOf { allowed, .. } => {
+                format!("{} must be one of: {}", label, allowed.join(", "))
             }
             ErrorKind::Custom { message, .. } => message.clone(),
+            ErrorKind::CustomCode { .. } => format!("{} is not valid", label),
         }
     }
 }
 
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.default_message())
+    }
+}
+
+/// Error key used for form-level errors that don't belong to a single field.
+///
+/// Struct-level validators (`#[form_validator(...)]`) can return errors created
+/// with `ValidationError::form`, which are stored under this key.
+pub const FORM_ERROR_KEY: &str = "_form";
+
+/// How serious a validation failure is.
+///
+/// Warnings are shown to the user like errors, but don't make
+/// `Form::validate` return false.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// The value is invalid and blocks submission
+    #[default]
+    Error,
+    /// The value is allowed but worth pointing out
+    Warning,
+}
+
 /// Represents a validation error for a specific field.
 ///
 /// This type is returned when validation fails, containing both the field name
@@ -139,14 +715,27 @@ impl ErrorKind {
 /// let error = ValidationError::new("email", "Email is required");
 /// assert_eq!(error.message(), "Email is required");
 /// ```
-#[derive(Clone, Debug)]
+///
+/// With the `serde` feature enabled, errors (and maps of them returned by
+/// `validate_all`) can be serialized, e.g. to send them back from a server
+/// function.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidationError {
     /// The name of the field that failed validation
     pub field: String,
     /// The human-readable error message (for backward compatibility)
     pub message: String,
     /// The structured error kind (for i18n)
-    pub kind: ErrorKind,
+    ///
+    /// It is boxed to keep `ValidationResult` small, as every validator
+    /// returns one.
+    pub kind: Box<ErrorKind>,
+    /// Optional machine-readable key for looking up a translated message
+    pub message_key: Option<String>,
+    /// Whether the failure blocks submission
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: Severity,
 }
 
 impl ValidationError {
@@ -162,10 +751,49 @@ impl ValidationError {
         Self {
             field: field.clone(),
             message: message.clone(),
-            kind: ErrorKind::Custom { field, message },
+            kind: Box::new(ErrorKind::Custom { field, message }),
+            message_key: None,
+            severity: Severity::Error,
         }
     }
 
+    /// Create a form-level validation error (not tied to a single field).
+    ///
+    /// The error is stored under `FORM_ERROR_KEY` in the form's error map.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: A human-readable error message
+    pub fn form(message: impl Into<String>) -> Self {
+        Self::new(FORM_ERROR_KEY, message)
+    }
+
+    /// Create a custom validation error identified by a code.
+    ///
+    /// Unlike `new`, the error carries no fixed message, so it can be
+    /// translated by its code like the built-in errors. Add interpolation
+    /// values with `with_param`.
+    ///
+    /// # Parameters
+    ///
+    /// - `field`: The name of the field that failed validation
+    /// - `code`: A stable identifier for the error, e.g. `"username_taken"`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let error = ValidationError::custom_code("username", "username_taken")
+    ///     .with_param("suggestion", "jed2");
+    /// assert_eq!(error.custom_code_name(), Some("username_taken"));
+    /// ```
+    pub fn custom_code(field: impl Into<String>, code: impl Into<String>) -> Self {
+        Self::with_kind(ErrorKind::CustomCode {
+            field: field.into(),
+            code: code.into(),
+            params: HashMap::new(),
+        })
+    }
+
     /// Create a new validation error with an error kind.
     ///
     /// # Parameters
@@ -177,20 +805,111 @@ impl ValidationError {
         Self {
             field,
             message,
-            kind,
+            kind: Box::new(kind),
+            message_key: None,
+            severity: Severity::Error,
         }
     }
 
+    /// Rebuild the default message using a human-friendly field label.
+    ///
+    /// This is used for fields declared with `#[field(label = "...")]`.
+    /// Apply it before `with_message`, which replaces the message entirely.
+    ///
+    /// # Parameters
+    ///
+    /// - `label`: The field's label, e.g. `"Email address"`
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.message = self.kind.default_message_with_label(label);
+        self
+    }
+
+    /// Replace the error message while keeping the error kind.
+    ///
+    /// This is used by validators with a `message = "..."` override.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: A human-readable error message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Attach a machine-readable i18n key to this error.
+    ///
+    /// This is used by validators with a `message_key = "..."` option, so
+    /// translators can dispatch on keys instead of matching on `ErrorKind`.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: The message key, e.g. `"errors.invalid_email"`
+    pub fn with_message_key(mut self, key: impl Into<String>) -> Self {
+        self.message_key = Some(key.into());
+        self
+    }
+
+    /// Add a parameter to interpolate into the translated message.
+    ///
+    /// Only `ErrorKind::CustomCode` errors carry parameters; for other kinds
+    /// this does nothing.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The parameter name used in the translation
+    /// - `value`: The value to interpolate
+    pub fn with_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        if let ErrorKind::CustomCode { params, .. } = &mut *self.kind {
+            params.insert(name.into(), value.into());
+        }
+        self
+    }
+
+    /// Set how serious the failure is.
+    ///
+    /// This is used by validators with a `severity = "warning"` option.
+    ///
+    /// # Parameters
+    ///
+    /// - `severity`: The severity of the failure
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Check whether this is a warning rather than a blocking error.
+    pub fn is_warning(&self) -> bool {
+        self.severity == Severity::Warning
+    }
+
     /// Get the error message.
     pub fn message(&self) -> &str {
         &self.message
     }
 
+    /// Get the stable, machine-readable error code, e.g. `"length.min"`.
+    ///
+    /// See `ErrorKind::code` for the list of codes.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// Get the code of an error created with `custom_code`, e.g.
+    /// `"username_taken"`.
+    pub fn custom_code_name(&self) -> Option<&str> {
+        self.kind.custom_code_name()
+    }
+
     /// Get the error kind.
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
     }
 
+    /// Get the i18n message key, if one was set.
+    pub fn message_key(&self) -> Option<&str> {
+        self.message_key.as_deref()
+    }
+
     /// Translate the error message using a provided translator function.
     ///
     /// This allows you to provide custom i18n logic without coupling
@@ -214,6 +933,312 @@ impl ValidationError {
     }
 }
 
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Get the current date in ISO 8601 (`YYYY-MM-DD`) format.
+///
+/// In the browser this is the user's local date. Elsewhere it is the UTC date.
+/// Used by the `date(after_today)` and `date(before_today)` validators.
+pub fn today() -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let now = leptos::web_sys::js_sys::Date::new_0();
+        format!(
+            "{:04}-{:02}-{:02}",
+            now.get_full_year(),
+            now.get_month() + 1,
+            now.get_date()
+        )
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let days = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() / 86_400)
+            .unwrap_or(0) as i64;
+
+        // Convert days since the Unix epoch to a civil date
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// How strictly the `email` validator checks addresses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmailMode {
+    /// A pragmatic check: `local@domain.tld` with a common set of characters
+    #[default]
+    Simple,
+    /// The browser's own check for `<input type="email">`, per the HTML spec
+    Html5,
+    /// The RFC 5322 addr-spec: dot-atom or quoted local parts, and domain
+    /// names or IPv4 literals, within the RFC 5321 length limits
+    Rfc5322,
+}
+
+/// Email pattern used by the `email` validator, compiled once on first use
+static EMAIL_PATTERN: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap()
+});
+
+/// Email pattern from the HTML spec's definition of a valid email address
+static HTML5_EMAIL_PATTERN: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$",
+    )
+    .unwrap()
+});
+
+/// Email pattern for the RFC 5322 addr-spec, without comments or folding whitespace
+static RFC5322_EMAIL_PATTERN: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r#"^(?:[a-zA-Z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-zA-Z0-9!#$%&'*+/=?^_`{|}~-]+)*|"(?:[\x01-\x08\x0b\x0c\x0e-\x1f\x20\x21\x23-\x5b\x5d-\x7f]|\\[\x01-\x09\x0b\x0c\x0e-\x7f])*")@(?:(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?\.)+[a-zA-Z0-9](?:[a-zA-Z0-9-]*[a-zA-Z0-9])?|\[(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\])$"#,
+    )
+    .unwrap()
+});
+
+/// Hyphenated UUID pattern used by the `uuid` validator, compiled once on first use
+static UUID_PATTERN: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+    )
+    .unwrap()
+});
+
+/// Check whether a string is an email address, as the `email` validator does.
+pub fn is_valid_email(value: &str) -> bool {
+    is_valid_email_with(value, EmailMode::Simple)
+}
+
+/// Check whether a string is an email address under the given strictness,
+/// as `email(mode = "...")` does.
+pub fn is_valid_email_with(value: &str, mode: EmailMode) -> bool {
+    match mode {
+        EmailMode::Simple => EMAIL_PATTERN.is_match(value),
+        EmailMode::Html5 => HTML5_EMAIL_PATTERN.is_match(value),
+        EmailMode::Rfc5322 => {
+            // RFC 5321 limits the local part to 64 octets and the path to 256,
+            // which leaves 254 for the address itself
+            let local_len = value.rfind('@').unwrap_or(value.len());
+            value.len() <= 254 && local_len <= 64 && RFC5322_EMAIL_PATTERN.is_match(value)
+        }
+    }
+}
+
+/// Check whether a string is a hyphenated UUID, as the `uuid` validator does.
+pub fn is_valid_uuid(value: &str) -> bool {
+    UUID_PATTERN.is_match(value)
+}
+
+/// Check whether a string is a hex color like `#ff8800` or `#f80`, as the
+/// `hex_color` validator does.
+pub fn is_valid_hex_color(value: &str) -> bool {
+    value.strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 6) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+/// Check whether a string is a date in ISO 8601 (`YYYY-MM-DD`) format.
+///
+/// This only checks the shape and the month/day ranges, not whether the day
+/// exists in that month.
+pub fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let well_formed = bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        });
+    if !well_formed {
+        return false;
+    }
+
+    let month: u32 = value[5..7].parse().unwrap_or(0);
+    let day: u32 = value[8..10].parse().unwrap_or(0);
+    (1..=12).contains(&month) && (1..=31).contains(&day)
+}
+
+/// Count the length of a string as the `length` validator sees it.
+///
+/// This counts characters (Unicode scalar values), or grapheme clusters when
+/// the `graphemes` feature is enabled, so non-ASCII input isn't measured in
+/// bytes. Use `length(bytes = true)` to count bytes instead.
+pub fn text_length(value: &str) -> usize {
+    #[cfg(feature = "graphemes")]
+    {
+        unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+    }
+
+    #[cfg(not(feature = "graphemes"))]
+    {
+        value.chars().count()
+    }
+}
+
+/// List the password requirements that a value does not meet.
+///
+/// The value must be at least `min_len` characters (see [`text_length`]) and use
+/// at least `min_classes` of the lowercase, uppercase, digit and symbol
+/// character classes. Digits are ASCII `0-9`; symbols are any other character
+/// that is neither alphanumeric nor whitespace. Used by the `password` validator.
+pub fn password_missing(
+    value: &str,
+    min_len: usize,
+    min_classes: usize,
+) -> Vec<PasswordRequirement> {
+    let mut missing = Vec::new();
+    if text_length(value) < min_len {
+        missing.push(PasswordRequirement::MinLength(min_len));
+    }
+
+    let classes = [
+        (
+            PasswordRequirement::Lowercase,
+            value.chars().any(char::is_lowercase),
+        ),
+        (
+            PasswordRequirement::Uppercase,
+            value.chars().any(char::is_uppercase),
+        ),
+        (
+            PasswordRequirement::Digit,
+            value.chars().any(|c| c.is_ascii_digit()),
+        ),
+        (
+            PasswordRequirement::Symbol,
+            value
+                .chars()
+                .any(|c| !c.is_alphanumeric() && !c.is_whitespace()),
+        ),
+    ];
+    let used = classes.iter().filter(|(_, present)| *present).count();
+    if used < min_classes {
+        missing.extend(
+            classes
+                .iter()
+                .filter(|(_, present)| !present)
+                .map(|(req, _)| *req),
+        );
+    }
+
+    missing
+}
+
+/// Bytes in a megabyte, as used by the `file(max_size_mb = ...)` validator.
+pub const BYTES_PER_MEGABYTE: u64 = 1024 * 1024;
+
+/// Metadata of a file selected in a `FileInput`.
+///
+/// Form structs hold this rather than the file contents, so validators can check
+/// the name, size and type. The selected `web_sys::File`s are available from
+/// `Form::files`. Use `Option<FileInfo>` for an optional file and
+/// `Vec<FileInfo>` for several.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileInfo {
+    /// The file name, without a path
+    pub name: String,
+    /// The size in bytes
+    pub size: u64,
+    /// The MIME type, empty when the browser does not know it
+    pub mime_type: String,
+}
+
+impl FileInfo {
+    /// Check whether this file matches an HTML `accept` list, e.g.
+    /// `"image/*,.pdf"`.
+    ///
+    /// Each comma-separated entry is a file extension, a MIME type, or a MIME
+    /// type wildcard like `image/*`.
+    pub fn is_accepted(&self, accept: &str) -> bool {
+        accept
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .any(|entry| {
+                if entry.starts_with('.') {
+                    self.name.to_lowercase().ends_with(&entry.to_lowercase())
+                } else if let Some(prefix) = entry.strip_suffix("/*") {
+                    self.mime_type
+                        .split_once('/')
+                        .is_some_and(|(kind, _)| kind.eq_ignore_ascii_case(prefix))
+                } else {
+                    self.mime_type.eq_ignore_ascii_case(entry)
+                }
+            })
+    }
+}
+
+/// An RGB color, as picked in a `ColorInput`.
+///
+/// Its field value is the lowercase `#rrggbb` form `<input type="color">`
+/// uses. The short `#rgb` form is read too. The default is black.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl HexColor {
+    /// Create a color from its red, green and blue components
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+impl std::fmt::Display for HexColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+/// Check the files of a `FileInput` field against a size limit and an `accept`
+/// list, returning the error for the first file that fails.
+///
+/// Used by the `file` validator.
+pub fn check_files(
+    field_name: &str,
+    value: &str,
+    max_size: Option<u64>,
+    accept: Option<&str>,
+) -> Option<ErrorKind> {
+    let files = Vec::<FileInfo>::from_field_value(field_name, value).unwrap_or_default();
+    files.into_iter().find_map(|file| {
+        if let Some(max_size) = max_size.filter(|max_size| file.size > *max_size) {
+            return Some(ErrorKind::FileTooLarge {
+                field: field_name.to_string(),
+                file: file.name,
+                max_size,
+            });
+        }
+        if let Some(accept) = accept.filter(|accept| !file.is_accepted(accept)) {
+            return Some(ErrorKind::InvalidFileType {
+                field: field_name.to_string(),
+                file: file.name,
+                accept: accept.to_string(),
+            });
+        }
+        None
+    })
+}
+
 /// Result type for validation operations.
 ///
 /// Returns `Ok(())` if validation succeeds, or `Err(ValidationError)` if it fails.
@@ -230,9 +1255,28 @@ pub type ValidationResult = Result<(), ValidationError>;
 /// The following types have built-in implementations:
 ///
 /// - `String` - Direct pass-through
-/// - `i32`, `i64`, `u32`, `u64` - Integer parsing
+/// - `char` - Exactly one character, so use `Option<char>` for a field that
+///   starts out empty
+/// - `PathBuf` - Direct pass-through
+/// - `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, `usize` -
+///   Integer parsing
+/// - `NonZeroU32` and the other `std::num` non-zero integers - Integer parsing
+///   that rejects 0. They have no `Default`, so forms hold them in an `Option`
+///   or implement `Default` themselves
 /// - `f32`, `f64` - Floating-point parsing
 /// - `bool` - Checkbox/toggle support ("on", "true", "1" = true)
+/// - `Vec<T>` - Comma-separated list of `T` values, with commas, backslashes
+///   and edge whitespace inside items escaped with a backslash
+/// - `Option<T>` - Empty input is `None`, anything else is parsed as `T`
+/// - `uuid::Uuid` - With the `uuid` feature enabled
+/// - `url::Url` - Absolute URLs, with the `url` feature enabled
+/// - `rust_decimal::Decimal` - Exact decimal numbers for money and other
+///   amounts `f64` can't hold, with the `decimal` feature enabled
+/// - `chrono::NaiveDate` - `YYYY-MM-DD`, with the `chrono` feature enabled
+/// - `chrono::NaiveDateTime` - `YYYY-MM-DDTHH:MM`, optionally with seconds,
+///   with the `chrono` feature enabled
+/// - `FileInfo` - The metadata a `FileInput` stores for a selected file
+/// - `HexColor` - `#rrggbb` or `#rgb`, as picked in a `ColorInput`
 ///
 /// # Custom Types
 ///
@@ -274,6 +1318,9 @@ pub type ValidationResult = Result<(), ValidationError>;
 /// }
 /// ```
 ///
+/// Fieldless enums like this can use `#[derive(FromFieldValue)]` instead, with
+/// `#[field_value = "us"]` on variants whose string differs from their name.
+///
 /// ## Example: Newtype with Validation
 ///
 /// ```rust,ignore
@@ -297,29 +1344,6 @@ pub type ValidationResult = Result<(), ValidationError>;
 /// }
 /// ```
 ///
-/// ## Example: Optional Fields
-///
-/// ```rust,ignore
-/// use borang::{FromFieldValue, ValidationError};
-///
-/// impl<T: FromFieldValue> FromFieldValue for Option<T> {
-///     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
-///         if value.trim().is_empty() {
-///             Ok(None)
-///         } else {
-///             T::from_field_value(field_name, value).map(Some)
-///         }
-///     }
-///
-///     fn to_field_value(&self) -> String {
-///         match self {
-///             Some(v) => v.to_field_value(),
-///             None => String::new(),
-///         }
-///     }
-/// }
-/// ```
-///
 /// # Error Handling
 ///
 /// Parse errors are treated as validation errors and appear in the form's error state
@@ -364,6 +1388,27 @@ pub trait FromFieldValue: Sized {
     fn to_field_value(&self) -> String;
 }
 
+/// Trait for field types with a fixed set of values, such as fieldless enums.
+///
+/// `#[derive(FromFieldValue)]` implements it, so `Select`, `MultiSelect` and
+/// `CheckboxGroup` can list a field's choices without a hand-written copy that
+/// drifts from the enum.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// view! {
+///     <Field form=form name="country" let:field_state>
+///         <Select state=field_state options=Country::field_options() />
+///     </Field>
+/// }
+/// ```
+pub trait FieldOptions {
+    /// Get every value with its display label, as `(value, label)` pairs in
+    /// declaration order.
+    fn field_options() -> Vec<(String, String)>;
+}
+
 // Implement for String
 impl FromFieldValue for String {
     fn from_field_value(_field_name: &str, value: &str) -> Result<Self, ValidationError> {
@@ -375,8 +1420,89 @@ impl FromFieldValue for String {
     }
 }
 
-// Implement for i32
-impl FromFieldValue for i32 {
+// Implement for char, which must be exactly one character
+impl FromFieldValue for char {
+    fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(ValidationError::with_kind(ErrorKind::ParseError {
+                field: field_name.to_string(),
+                expected_type: "single character".to_string(),
+            })),
+        }
+    }
+
+    fn to_field_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+// Implement for PathBuf - any text is a path
+impl FromFieldValue for std::path::PathBuf {
+    fn from_field_value(_field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        Ok(std::path::PathBuf::from(value))
+    }
+
+    fn to_field_value(&self) -> String {
+        self.to_string_lossy().into_owned()
+    }
+}
+
+// Implement for the integer types, and the non-zero ones, which reject 0 like
+// any other unparseable value
+macro_rules! impl_from_field_value_integer {
+    ($expected_type:literal: $($ty:ty),*) => {
+        $(
+            impl FromFieldValue for $ty {
+                fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+                    value.parse().map_err(|_| {
+                        ValidationError::with_kind(ErrorKind::ParseError {
+                            field: field_name.to_string(),
+                            expected_type: $expected_type.to_string(),
+                        })
+                    })
+                }
+
+                fn to_field_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field_value_integer!(
+    "number": i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize
+);
+impl_from_field_value_integer!(
+    "non-zero number": std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128,
+    std::num::NonZeroIsize,
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroUsize
+);
+
+// Implement for f32
+impl FromFieldValue for f32 {
     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
         value.parse().map_err(|_| {
             ValidationError::with_kind(ErrorKind::ParseError {
@@ -391,8 +1517,8 @@ impl FromFieldValue for i32 {
     }
 }
 
-// Implement for i64
-impl FromFieldValue for i64 {
+// Implement for f64
+impl FromFieldValue for f64 {
     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
         value.parse().map_err(|_| {
             ValidationError::with_kind(ErrorKind::ParseError {
@@ -407,13 +1533,26 @@ impl FromFieldValue for i64 {
     }
 }
 
-// Implement for u32
-impl FromFieldValue for u32 {
+// Implement for bool
+impl FromFieldValue for bool {
+    fn from_field_value(_field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        // For checkboxes: "on" or "true" = true, anything else = false
+        Ok(value == "on" || value == "true" || value == "1")
+    }
+
+    fn to_field_value(&self) -> String {
+        if *self { "true" } else { "false" }.to_string()
+    }
+}
+
+// Implement for uuid::Uuid
+#[cfg(feature = "uuid")]
+impl FromFieldValue for uuid::Uuid {
     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
-        value.parse().map_err(|_| {
+        uuid::Uuid::parse_str(value.trim()).map_err(|_| {
             ValidationError::with_kind(ErrorKind::ParseError {
                 field: field_name.to_string(),
-                expected_type: "number".to_string(),
+                expected_type: "uuid".to_string(),
             })
         })
     }
@@ -423,13 +1562,14 @@ impl FromFieldValue for u32 {
     }
 }
 
-// Implement for u64
-impl FromFieldValue for u64 {
+// Implement for url::Url (absolute URLs, as <input type="url"> accepts)
+#[cfg(feature = "url")]
+impl FromFieldValue for url::Url {
     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
-        value.parse().map_err(|_| {
+        url::Url::parse(value.trim()).map_err(|_| {
             ValidationError::with_kind(ErrorKind::ParseError {
                 field: field_name.to_string(),
-                expected_type: "number".to_string(),
+                expected_type: "URL".to_string(),
             })
         })
     }
@@ -439,13 +1579,14 @@ impl FromFieldValue for u64 {
     }
 }
 
-// Implement for f32
-impl FromFieldValue for f32 {
+// Implement for rust_decimal::Decimal (plain notation, no exponent)
+#[cfg(feature = "decimal")]
+impl FromFieldValue for rust_decimal::Decimal {
     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
-        value.parse().map_err(|_| {
+        value.trim().parse::<rust_decimal::Decimal>().map_err(|_| {
             ValidationError::with_kind(ErrorKind::ParseError {
                 field: field_name.to_string(),
-                expected_type: "number".to_string(),
+                expected_type: "decimal number".to_string(),
             })
         })
     }
@@ -455,34 +1596,197 @@ impl FromFieldValue for f32 {
     }
 }
 
-// Implement for f64
-impl FromFieldValue for f64 {
+// Implement for chrono::NaiveDate (the format used by <input type="date">)
+#[cfg(feature = "chrono")]
+impl FromFieldValue for chrono::NaiveDate {
     fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
-        value.parse().map_err(|_| {
+        chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
             ValidationError::with_kind(ErrorKind::ParseError {
                 field: field_name.to_string(),
-                expected_type: "number".to_string(),
+                expected_type: "date".to_string(),
             })
         })
     }
 
+    fn to_field_value(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+// Implement for chrono::NaiveDateTime (the format used by <input type="datetime-local">)
+#[cfg(feature = "chrono")]
+impl FromFieldValue for chrono::NaiveDateTime {
+    fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+            .map_err(|_| {
+                ValidationError::with_kind(ErrorKind::ParseError {
+                    field: field_name.to_string(),
+                    expected_type: "date and time".to_string(),
+                })
+            })
+    }
+
+    fn to_field_value(&self) -> String {
+        use chrono::Timelike;
+
+        // Browsers only show seconds when the value has them
+        if self.second() == 0 && self.nanosecond() == 0 {
+            self.format("%Y-%m-%dT%H:%M").to_string()
+        } else {
+            self.format("%Y-%m-%dT%H:%M:%S").to_string()
+        }
+    }
+}
+
+// Implement for HexColor (the format used by <input type="color">)
+impl FromFieldValue for HexColor {
+    fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        let value = value.trim();
+        let parse_error = || {
+            ValidationError::with_kind(ErrorKind::ParseError {
+                field: field_name.to_string(),
+                expected_type: "hex color".to_string(),
+            })
+        };
+        if !is_valid_hex_color(value) {
+            return Err(parse_error());
+        }
+        let digits = &value[1..];
+        // Expand the short form, so `#f80` is `#ff8800`
+        let digits = if digits.len() == 3 {
+            digits.chars().flat_map(|c| [c, c]).collect()
+        } else {
+            digits.to_string()
+        };
+        let component = |index: usize| {
+            u8::from_str_radix(&digits[index..index + 2], 16).map_err(|_| parse_error())
+        };
+        Ok(HexColor::new(component(0)?, component(2)?, component(4)?))
+    }
+
     fn to_field_value(&self) -> String {
         self.to_string()
     }
 }
 
-// Implement for bool
-impl FromFieldValue for bool {
-    fn from_field_value(_field_name: &str, value: &str) -> Result<Self, ValidationError> {
-        // For checkboxes: "on" or "true" = true, anything else = false
-        Ok(value == "on" || value == "true" || value == "1")
+// Implement for FileInfo, url-encoded so a Vec<FileInfo> stays comma-separated
+impl FromFieldValue for FileInfo {
+    fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        let mut file = FileInfo::default();
+        for (key, value) in form_urlencoded::parse(value.trim().as_bytes()) {
+            match key.as_ref() {
+                "name" => file.name = value.into_owned(),
+                "size" => {
+                    file.size = value.parse().map_err(|_| {
+                        ValidationError::with_kind(ErrorKind::ParseError {
+                            field: field_name.to_string(),
+                            expected_type: "file".to_string(),
+                        })
+                    })?
+                }
+                "type" => file.mime_type = value.into_owned(),
+                _ => {}
+            }
+        }
+        Ok(file)
     }
 
     fn to_field_value(&self) -> String {
-        if *self { "true" } else { "false" }.to_string()
+        // No file selected
+        if self.name.is_empty() {
+            return String::new();
+        }
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair("name", &self.name)
+            .append_pair("size", &self.size.to_string())
+            .append_pair("type", &self.mime_type)
+            .finish()
     }
 }
 
+// Implement for Option<T> - empty (or whitespace-only) input is None
+impl<T: FromFieldValue> FromFieldValue for Option<T> {
+    fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        if value.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::from_field_value(field_name, value).map(Some)
+        }
+    }
+
+    fn to_field_value(&self) -> String {
+        match self {
+            Some(v) => v.to_field_value(),
+            None => String::new(),
+        }
+    }
+}
+
+// Implement for Vec<T> as a comma-separated list
+//
+// Commas and backslashes inside an item, and whitespace at its edges, are
+// escaped with a backslash, e.g. `Kuala Lumpur\, MY,Penang`. Unescaped
+// whitespace around items is trimmed and empty items are dropped, so typed
+// lists like `a, b,` work too.
+impl<T: FromFieldValue> FromFieldValue for Vec<T> {
+    fn from_field_value(field_name: &str, value: &str) -> Result<Self, ValidationError> {
+        split_list(value)
+            .iter()
+            .map(|item| T::from_field_value(field_name, item))
+            .collect()
+    }
+
+    fn to_field_value(&self) -> String {
+        self.iter()
+            .map(|item| escape_list_item(&item.to_field_value()))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Escape an item of a `Vec` field's value, so it survives `split_list`
+fn escape_list_item(item: &str) -> String {
+    let start = item.len() - item.trim_start().len();
+    let end = item.trim_end().len();
+    let mut escaped = String::with_capacity(item.len());
+    for (index, c) in item.char_indices() {
+        let at_edge = index < start || index >= end;
+        if c == ',' || c == '\\' || (at_edge && c.is_whitespace()) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Split a `Vec` field's value into its unescaped items
+fn split_list(value: &str) -> Vec<String> {
+    // Characters of each item, with whether they were escaped
+    let mut items = vec![Vec::new()];
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next().unwrap_or('\\');
+                items.last_mut().unwrap().push((escaped, true));
+            }
+            ',' => items.push(Vec::new()),
+            _ => items.last_mut().unwrap().push((c, false)),
+        }
+    }
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let is_text = |&(c, escaped): &(char, bool)| escaped || !c.is_whitespace();
+            let start = item.iter().position(is_text)?;
+            let end = item.len() - item.iter().rev().position(is_text)?;
+            Some(item[start..end].iter().map(|&(c, _)| c).collect())
+        })
+        .collect()
+}
+
 /// Trait that form structs implement (via derive macro).
 ///
 /// This trait is automatically implemented when you use `#[derive(FormValidation)]`
@@ -538,6 +1842,18 @@ pub trait FormValidation {
     /// - `Err(ValidationError)` if validation fails
     fn validate_field(&self, field_name: &str) -> ValidationResult;
 
+    /// Validate a specific field by name, collecting every failure.
+    ///
+    /// Unlike `validate_field`, this runs all of the field's validators instead
+    /// of stopping at the first error, so a UI can list every unmet requirement.
+    ///
+    /// # Returns
+    ///
+    /// The errors in validator order. An empty vector means the field is valid.
+    fn validate_field_all(&self, field_name: &str) -> Vec<ValidationError> {
+        self.validate_field(field_name).err().into_iter().collect()
+    }
+
     /// Get all field names defined in the form.
     ///
     /// # Returns
@@ -545,6 +1861,86 @@ pub trait FormValidation {
     /// A vector of static string slices containing all field names.
     fn field_names() -> Vec<&'static str>;
 
+    /// Get the names of this data's fields, in declaration order.
+    ///
+    /// The same as `field_names`, except for data whose fields are only known
+    /// at runtime, like `DynamicData`, which lists its schema's fields.
+    fn data_field_names(&self) -> Vec<String> {
+        Self::field_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Get information about every field, in declaration order.
+    ///
+    /// The derive fills in each field's type and validators. Otherwise this
+    /// falls back to `field_names`, `field_label` and `is_field_required`.
+    fn field_meta() -> Vec<FieldMeta> {
+        Self::field_names()
+            .into_iter()
+            .map(|name| FieldMeta {
+                name,
+                type_name: "",
+                label: Self::field_label(name),
+                required: Self::is_field_required(name),
+                validators: Vec::new(),
+                compound: false,
+            })
+            .collect()
+    }
+
+    /// Get the human-friendly label of a field, if it declares one.
+    ///
+    /// Labels come from `#[field(label = "...")]` and are used in default
+    /// error messages and by the `Label` component.
+    fn field_label(_field_name: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// Check whether a field must be filled in.
+    ///
+    /// Fields with a `required` validator that isn't a warning are required.
+    /// The bundled components use this to set `aria-required`.
+    fn is_field_required(_field_name: &str) -> bool {
+        false
+    }
+
+    /// Get the numeric limits of a field.
+    ///
+    /// They come from the field's `range` and `multiple_of` validators that
+    /// aren't warnings. The bundled `NumberInput` uses them for its attributes.
+    fn number_bounds(_field_name: &str) -> NumberBounds {
+        NumberBounds::default()
+    }
+
+    /// Get the text limits of a field.
+    ///
+    /// They come from the field's `length`, `pattern` and `password`
+    /// validators that aren't warnings, leaving out `length(bytes)`. The
+    /// bundled `Input` and `Textarea` use them for their attributes, and
+    /// `PasswordInput` for its strength meter.
+    fn text_bounds(_field_name: &str) -> TextBounds {
+        TextBounds::default()
+    }
+
+    /// Get how a field's value is shown in its input.
+    ///
+    /// Formats come from `#[field(mask = "...")]`. The `Field` component's
+    /// `format` prop overrides this.
+    fn field_format(_field_name: &str) -> Option<FieldFormat> {
+        None
+    }
+
+    /// Get the fields to revalidate when a field is validated.
+    ///
+    /// Dependencies come from `#[field(depends_on = "...")]`, e.g. a password
+    /// confirmation depending on the password. `Form::validate_field` cascades
+    /// to these fields, along with any added with `Form::add_dependency`.
+    fn field_dependents(_field_name: &str) -> Vec<&'static str> {
+        Vec::new()
+    }
+
     /// Sync field values from string map (called by Form).
     ///
     /// This method is called internally by the form system to convert string
@@ -574,4 +1970,76 @@ pub trait FormValidation {
     ///
     /// A map of field names to their string representations.
     fn to_strings(&self) -> HashMap<String, String>;
+
+    /// Get the blank data a form is cleared to.
+    ///
+    /// `Form::reset` and `Form::load_json` start from this instead of
+    /// `Self::default()`, so data that carries more than its values, like
+    /// `DynamicData` and its schema, can keep it. Defaults to `Self::default()`.
+    fn cleared(&self) -> Self
+    where
+        Self: Default + Sized,
+    {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_email_pattern() {
+        for valid in [
+            "jed@email.com",
+            "jed.saw+forms@mail.example.my",
+            "a_b%c@x-y.io",
+        ] {
+            assert!(is_valid_email(valid), "{}", valid);
+        }
+        for invalid in [
+            "",
+            "jed",
+            "jed@email",
+            "@email.com",
+            "jed@email.c",
+            "jed saw@email.com",
+            "jed@email.com ",
+            "jed@@email.com",
+        ] {
+            assert!(!is_valid_email(invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn html5_email_pattern() {
+        let valid = |value: &str| is_valid_email_with(value, EmailMode::Html5);
+        // The HTML spec allows dotless domains and symbols Simple rejects
+        assert!(valid("jed@localhost"));
+        assert!(valid("jed!#$&'*/=?^`{|}~@email.com"));
+        assert!(valid("jed@a-1.email.com"));
+        assert!(!valid("jed@-email.com"));
+        assert!(!valid("jed@email-.com"));
+        assert!(!valid(&format!("jed@{}.com", "a".repeat(64))));
+        assert!(!valid("\"jed\"@email.com"));
+        assert!(!valid("jed@[127.0.0.1]"));
+    }
+
+    #[test]
+    fn rfc5322_email_pattern() {
+        let valid = |value: &str| is_valid_email_with(value, EmailMode::Rfc5322);
+        assert!(valid("jed.saw@email.com"));
+        assert!(valid(r#""jed saw"@email.com"#));
+        assert!(valid(r#""jed\"saw"@email.com"#));
+        assert!(valid("jed@[192.168.0.1]"));
+        assert!(!valid("jed..saw@email.com"));
+        assert!(!valid(".jed@email.com"));
+        assert!(!valid("jed@[256.0.0.1]"));
+        assert!(!valid("jed@email..com"));
+        // RFC 5321 length limits
+        assert!(valid(&format!("{}@email.com", "a".repeat(64))));
+        assert!(!valid(&format!("{}@email.com", "a".repeat(65))));
+        let domain = format!("{}.com", vec!["a".repeat(60); 5].join("."));
+        assert!(!valid(&format!("jed@{}", domain)));
+    }
 }